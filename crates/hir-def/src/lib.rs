@@ -99,9 +99,11 @@ use crate::{
     data::adt::VariantData,
     db::DefDatabase,
     item_tree::{
-        Const, Enum, ExternCrate, Function, Impl, ItemTreeId, ItemTreeNode, Macro2, MacroRules,
-        Static, Struct, Trait, TraitAlias, TypeAlias, Union, Use,
+        Const, Enum, ExternCrate, Function, Impl, ItemTree, ItemTreeId, ItemTreeNode, Macro2,
+        MacroRules, ModItem, ModKind, Static, Struct, Trait, TraitAlias, TreeId, TypeAlias, Union,
+        Use,
     },
+    nameres::ModuleOrigin,
 };
 
 /// A `ModuleId` that is always a crate's root module.
@@ -207,6 +209,82 @@ impl ModuleId {
     pub fn is_block_module(self) -> bool {
         self.block.is_some() && self.local_id == DefMap::ROOT
     }
+
+    /// Finds an item named `name` that is declared in this module, but disabled by a `#[cfg]`.
+    pub fn cfg_inactive_item(self, db: &dyn DefDatabase, name: &Name) -> Option<CfgInactiveItem> {
+        let def_map = self.def_map(db);
+        let origin = &def_map[self.local_id].origin;
+        let tree_id = match *origin {
+            ModuleOrigin::CrateRoot { definition } | ModuleOrigin::File { definition, .. } => {
+                TreeId::new(definition.into(), None)
+            }
+            ModuleOrigin::Inline { definition_tree_id, .. } => definition_tree_id.tree_id(),
+            ModuleOrigin::BlockExpr { .. } => return None,
+        };
+        let item_tree = tree_id.item_tree(db);
+        let items = match *origin {
+            ModuleOrigin::Inline { definition_tree_id, .. } => {
+                match &item_tree[definition_tree_id.value].kind {
+                    ModKind::Inline { items } => &**items,
+                    ModKind::Outline => return None,
+                }
+            }
+            _ => item_tree.top_level_items(),
+        };
+
+        let cfg_options = &db.crate_graph()[self.krate].cfg_options;
+        let item = items.iter().copied().find(|&item| {
+            mod_item_name(&item_tree, item) == Some(name)
+                && item_tree
+                    .attrs(db, self.krate, item.into())
+                    .cfg()
+                    .map_or(false, |cfg| cfg_options.check(&cfg) == Some(false))
+        })?;
+        Some(CfgInactiveItem { module: self, tree_id, item })
+    }
+}
+
+/// An item that is declared in a module but disabled by a `#[cfg]`.
+///
+/// These items are not part of the `DefMap`, so unlike other items they have no interned id and
+/// are only described by their location in the item tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CfgInactiveItem {
+    pub module: ModuleId,
+    pub tree_id: TreeId,
+    pub item: ModItem,
+}
+
+impl CfgInactiveItem {
+    pub fn name(self, db: &dyn DefDatabase) -> Name {
+        let item_tree = self.tree_id.item_tree(db);
+        mod_item_name(&item_tree, self.item).cloned().unwrap_or_else(Name::missing)
+    }
+
+    pub fn source(self, db: &dyn DefDatabase) -> InFile<ast::Item> {
+        let item_tree = self.tree_id.item_tree(db);
+        let ast_id = AstId::new(self.tree_id.file_id(), self.item.ast_id(&item_tree));
+        ast_id.to_in_file_node(db.upcast())
+    }
+
+    /// Whether this item lives in the value namespace, as opposed to the type namespace.
+    pub fn is_value(self) -> bool {
+        matches!(self.item, ModItem::Function(_) | ModItem::Const(_) | ModItem::Static(_))
+    }
+}
+
+fn mod_item_name(item_tree: &ItemTree, item: ModItem) -> Option<&Name> {
+    match item {
+        ModItem::Function(id) => Some(&item_tree[id].name),
+        ModItem::Struct(id) => Some(&item_tree[id].name),
+        ModItem::Union(id) => Some(&item_tree[id].name),
+        ModItem::Enum(id) => Some(&item_tree[id].name),
+        ModItem::Const(id) => item_tree[id].name.as_ref(),
+        ModItem::Static(id) => Some(&item_tree[id].name),
+        ModItem::Trait(id) => Some(&item_tree[id].name),
+        ModItem::TypeAlias(id) => Some(&item_tree[id].name),
+        _ => None,
+    }
 }
 
 /// An ID of a module, **local** to a `DefMap`.
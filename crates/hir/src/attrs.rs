@@ -2,14 +2,15 @@
 
 use std::ops::ControlFlow;
 
-use base_db::FileId;
+use base_db::{CrateId, FileId};
 use hir_def::{
     attr::AttrsWithOwner,
     item_scope::ItemInNs,
-    path::{ModPath, Path},
+    lang_item::LangItem,
+    path::{ModPath, Path, PathKind},
     per_ns::Namespace,
     resolver::{HasResolver, Resolver, TypeNs},
-    AssocItemId, AttrDefId, ModuleDefId,
+    AttrDefId, GenericDefId, ModuleDefId, TraitId,
 };
 use hir_expand::{
     name::Name,
@@ -19,9 +20,10 @@ use hir_ty::{db::HirDatabase, method_resolution};
 use syntax::{ast, AstNode};
 
 use crate::{
-    Adt, AsAssocItem, AssocItem, BuiltinType, Const, ConstParam, DocLinkDef, Enum, ExternCrateDecl,
-    Field, Function, GenericParam, HasCrate, Impl, LifetimeParam, Macro, Module, ModuleDef, Static,
-    Struct, Trait, TraitAlias, Type, TypeAlias, TypeParam, Union, Variant, VariantDef,
+    Adt, AsAssocItem, AssocItem, AssocItemContainer, BuiltinType, CfgInactiveItem, Const,
+    ConstParam, Crate, DocLinkDef, Enum, ExternCrateDecl, Field, Function, GenericParam, HasCrate,
+    HasVisibility, Impl, LifetimeParam, Macro, Module, ModuleDef, Static, Struct, Trait,
+    TraitAlias, Type, TypeAlias, TypeParam, Union, Variant, VariantDef,
 };
 
 pub trait HasAttrs {
@@ -94,22 +96,105 @@ impl HasAttrs for AssocItem {
     }
 }
 
+/// Compatibility options for [`resolve_doc_path_on`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DocPathResolveOptions {
+    /// Accept old rustdoc style links like `foo/Bar`, resolving them like `foo::Bar`.
+    pub legacy_slash_separators: bool,
+    /// Also resolve to items that are disabled by a `#[cfg]`.
+    pub include_cfg_inactive: bool,
+}
+
 /// Resolves the item `link` points to in the scope of `def`.
 pub fn resolve_doc_path_on(
     db: &dyn HirDatabase,
     def: impl HasAttrs,
     link: &str,
     ns: Option<Namespace>,
+    options: DocPathResolveOptions,
 ) -> Option<DocLinkDef> {
-    resolve_doc_path_on_(db, link, def.attr_id(), ns)
+    resolve_doc_path_on_(db, link, def.attr_id(), ns, options)
 }
 
-fn resolve_doc_path_on_(
+/// Like [`resolve_doc_path_on`], but only resolves to items that are visible from the module
+/// `def` is defined in, respecting restricted visibilities like `pub(in crate::foo)`.
+pub fn resolve_visible_doc_path_on(
     db: &dyn HirDatabase,
+    def: impl HasAttrs,
     link: &str,
-    attr_id: AttrDefId,
     ns: Option<Namespace>,
 ) -> Option<DocLinkDef> {
+    let attr_id = def.attr_id();
+    let module = Module::from(doc_owner_resolver(db, attr_id)?.module());
+    let resolved = resolve_doc_path_on_(db, link, attr_id, ns, DocPathResolveOptions::default())?;
+    let is_visible = match resolved {
+        DocLinkDef::ModuleDef(it) => it.is_visible_from(db, module),
+        DocLinkDef::Field(it) => it.is_visible_from(db, module),
+        DocLinkDef::SelfType(it) => it.is_visible_from(db, module),
+        DocLinkDef::CfgInactive(_) => false,
+    };
+    is_visible.then_some(resolved)
+}
+
+/// Like [`resolve_doc_path_on`], but also returns the crate the resolved item is defined in.
+///
+/// Returns `None` for links to builtin types, as these are not defined in any crate.
+pub fn resolve_doc_path_with_crate_on(
+    db: &dyn HirDatabase,
+    def: impl HasAttrs,
+    link: &str,
+    ns: Option<Namespace>,
+) -> Option<(DocLinkDef, Crate)> {
+    let resolved =
+        resolve_doc_path_on_(db, link, def.attr_id(), ns, DocPathResolveOptions::default())?;
+    let krate = match resolved {
+        DocLinkDef::ModuleDef(it) => it.module(db)?.krate(),
+        DocLinkDef::Field(it) => it.krate(db),
+        DocLinkDef::SelfType(it) => it.krate(db),
+        DocLinkDef::CfgInactive(it) => it.module().krate(),
+    };
+    Some((resolved, krate))
+}
+
+/// Resolves the longest prefix of `link` that points to an item in the scope of `def`, returning
+/// the item together with the unresolved remainder of the link.
+///
+/// For a partially typed link like `foo::ba` this resolves `foo` and returns `ba` as the
+/// remainder. The remainder is empty if the whole link resolves.
+pub fn resolve_doc_path_prefix<'a>(
+    db: &dyn HirDatabase,
+    def: impl HasAttrs,
+    link: &'a str,
+) -> Option<(DocLinkDef, &'a str)> {
+    let attr_id = def.attr_id();
+    let mut end = link.len();
+    loop {
+        let prefix = &link[..end];
+        if let Some(resolved) =
+            resolve_doc_path_on_(db, prefix, attr_id, None, DocPathResolveOptions::default())
+        {
+            let remaining = link[end..].strip_prefix("::").unwrap_or_default();
+            return Some((resolved, remaining));
+        }
+        end = prefix.rfind("::")?;
+    }
+}
+
+/// Returns whether the item a doc link resolved to is marked `#[deprecated]`.
+pub fn doc_link_def_is_deprecated(db: &dyn HirDatabase, def: DocLinkDef) -> bool {
+    let attrs = match def {
+        DocLinkDef::ModuleDef(it) => match it.attrs(db) {
+            Some(attrs) => attrs,
+            None => return false,
+        },
+        DocLinkDef::Field(it) => it.attrs(db),
+        DocLinkDef::SelfType(it) => it.attrs(db),
+        DocLinkDef::CfgInactive(_) => return false,
+    };
+    attrs.by_key("deprecated").exists()
+}
+
+fn doc_owner_resolver(db: &dyn HirDatabase, attr_id: AttrDefId) -> Option<Resolver> {
     let resolver = match attr_id {
         AttrDefId::ModuleId(it) => it.resolver(db.upcast()),
         AttrDefId::FieldId(it) => it.parent.resolver(db.upcast()),
@@ -128,12 +213,31 @@ fn resolve_doc_path_on_(
         AttrDefId::ExternCrateId(it) => it.resolver(db.upcast()),
         AttrDefId::GenericParamId(_) => return None,
     };
+    Some(resolver)
+}
 
-    let mut modpath = modpath_from_str(db, link)?;
+fn resolve_doc_path_on_(
+    db: &dyn HirDatabase,
+    link: &str,
+    attr_id: AttrDefId,
+    ns: Option<Namespace>,
+    options: DocPathResolveOptions,
+) -> Option<DocLinkDef> {
+    let resolver = doc_owner_resolver(db, attr_id)?;
+    let mut modpath = modpath_from_str(db, link, options.legacy_slash_separators)?;
 
     let resolved = resolver.resolve_module_path_in_items(db.upcast(), &modpath);
     if resolved.is_none() {
+        if let Some(trait_) = resolve_lang_item_trait(db, resolver.krate(), &modpath) {
+            return matches!(ns, None | Some(Namespace::Types))
+                .then(|| DocLinkDef::ModuleDef(ModuleDef::Trait(trait_.into())));
+        }
         let last_name = modpath.pop_segment()?;
+        if options.include_cfg_inactive {
+            if let Some(def) = resolve_cfg_inactive_item(db, &resolver, &modpath, &last_name, ns) {
+                return Some(def);
+            }
+        }
         resolve_assoc_or_field(db, resolver, modpath, last_name, ns)
     } else {
         let def = match ns {
@@ -150,6 +254,27 @@ fn resolve_doc_path_on_(
     }
 }
 
+fn resolve_cfg_inactive_item(
+    db: &dyn HirDatabase,
+    resolver: &Resolver,
+    qualifier: &ModPath,
+    name: &Name,
+    ns: Option<Namespace>,
+) -> Option<DocLinkDef> {
+    let module = if qualifier.kind == PathKind::Plain && qualifier.segments().is_empty() {
+        resolver.module()
+    } else {
+        match resolver.resolve_module_path_in_items(db.upcast(), qualifier).take_types()? {
+            ModuleDefId::ModuleId(it) => it,
+            _ => return None,
+        }
+    };
+    let item = module.cfg_inactive_item(db.upcast(), name)?;
+    let expected_ns = if item.is_value() { Namespace::Values } else { Namespace::Types };
+    (ns.unwrap_or(expected_ns) == expected_ns)
+        .then_some(DocLinkDef::CfgInactive(CfgInactiveItem { id: item }))
+}
+
 fn resolve_assoc_or_field(
     db: &dyn HirDatabase,
     resolver: Resolver,
@@ -157,14 +282,36 @@ fn resolve_assoc_or_field(
     name: Name,
     ns: Option<Namespace>,
 ) -> Option<DocLinkDef> {
+    let lang_item_trait = resolve_lang_item_trait(db, resolver.krate(), &path);
+    let last_segment = path.segments().last().cloned();
     let path = Path::from_known_path_with_no_generic(path);
-    // FIXME: This does not handle `Self` on trait definitions, which we should resolve to the
-    // trait itself.
-    let base_def = resolver.resolve_path_in_type_ns_fully(db.upcast(), &path)?;
+    let base_def = match resolver.resolve_path_in_type_ns_fully(db.upcast(), &path) {
+        Some(it) => it,
+        None => match resolver.resolve_path_in_type_ns(db.upcast(), &path) {
+            // `Self::Assoc` inside an impl, look the associated type up in the impl's items.
+            Some((TypeNs::SelfType(id), Some(1), _)) if path.segments().len() == 2 => {
+                let assoc_name = last_segment?;
+                let alias = Impl::from(id).items(db).into_iter().find_map(|it| match it {
+                    AssocItem::TypeAlias(alias) if alias.name(db) == assoc_name => Some(alias),
+                    _ => None,
+                })?;
+                TypeNs::TypeAliasId(alias.id)
+            }
+            _ => TypeNs::TraitId(lang_item_trait?),
+        },
+    };
 
     let ty = match base_def {
         TypeNs::SelfType(id) => Impl::from(id).self_ty(db),
-        TypeNs::GenericParam(_) => {
+        TypeNs::GenericParam(id) => {
+            // `Self` inside a trait definition refers to the trait's own items, be it methods,
+            // associated consts or associated types.
+            let parent = id.parent();
+            if let GenericDefId::TraitId(trait_) = parent {
+                if db.generic_params(parent).type_or_consts[id.local_id()].is_trait_self() {
+                    return resolve_trait_item(db, trait_, &name, ns);
+                }
+            }
             // Even if this generic parameter has some trait bounds, rustdoc doesn't
             // resolve `name` to trait items.
             return None;
@@ -177,26 +324,20 @@ fn resolve_assoc_or_field(
         }
         TypeNs::TypeAliasId(id) => {
             let alias = TypeAlias::from(id);
-            if alias.as_assoc_item(db).is_some() {
-                // We don't normalize associated type aliases, so we have nothing to
+            match alias.as_assoc_item(db).map(|it| it.container(db)) {
+                // We don't normalize associated type aliases of traits, so we have nothing to
                 // resolve `name` to.
-                return None;
+                Some(AssocItemContainer::Trait(_)) => return None,
+                // Associated types of impls are defined as a concrete type.
+                Some(AssocItemContainer::Impl(_)) | None => alias.ty(db),
             }
-            alias.ty(db)
         }
         TypeNs::BuiltinType(id) => BuiltinType::from(id).ty(db),
         TypeNs::TraitId(id) => {
             // Doc paths in this context may only resolve to an item of this trait
             // (i.e. no items of its supertraits), so we need to handle them here
             // independently of others.
-            return db.trait_data(id).items.iter().find(|it| it.0 == name).map(|(_, assoc_id)| {
-                let def = match *assoc_id {
-                    AssocItemId::FunctionId(it) => ModuleDef::Function(it.into()),
-                    AssocItemId::ConstId(it) => ModuleDef::Const(it.into()),
-                    AssocItemId::TypeAliasId(it) => ModuleDef::TypeAlias(it.into()),
-                };
-                DocLinkDef::ModuleDef(def)
-            });
+            return resolve_trait_item(db, id, &name, ns);
         }
         TypeNs::TraitAliasId(_) => {
             // XXX: Do these get resolved?
@@ -213,6 +354,12 @@ fn resolve_assoc_or_field(
         return Some(impl_trait_item_def);
     }
 
+    // Trait objects, also behind smart pointers like `Box<dyn Trait>`, expose the items of their
+    // principal trait.
+    if let Some(trait_) = ty.autoderef(db).find_map(|it| it.as_dyn_trait()) {
+        return resolve_trait_item(db, trait_.id, &name, ns);
+    }
+
     let variant_def = match ty.as_adt()? {
         Adt::Struct(it) => it.into(),
         Adt::Union(it) => it.into(),
@@ -221,6 +368,50 @@ fn resolve_assoc_or_field(
     resolve_field(db, variant_def, name, ns)
 }
 
+/// Lang-item traits that rustdoc resolves by name, even if they are not in scope.
+const LANG_ITEM_TRAITS: &[(&str, LangItem)] = &[
+    ("Fn", LangItem::Fn),
+    ("FnMut", LangItem::FnMut),
+    ("FnOnce", LangItem::FnOnce),
+    ("Sized", LangItem::Sized),
+    ("Copy", LangItem::Copy),
+    ("Clone", LangItem::Clone),
+    ("Sync", LangItem::Sync),
+    ("Drop", LangItem::Drop),
+    ("Deref", LangItem::Deref),
+    ("DerefMut", LangItem::DerefMut),
+    ("Add", LangItem::Add),
+    ("Sub", LangItem::Sub),
+    ("Mul", LangItem::Mul),
+    ("Div", LangItem::Div),
+    ("Rem", LangItem::Rem),
+    ("Neg", LangItem::Neg),
+    ("Not", LangItem::Not),
+    ("Index", LangItem::Index),
+    ("IndexMut", LangItem::IndexMut),
+];
+
+fn resolve_lang_item_trait(
+    db: &dyn HirDatabase,
+    krate: CrateId,
+    path: &ModPath,
+) -> Option<TraitId> {
+    let name = path.as_ident()?;
+    let &(_, lang_item) = LANG_ITEM_TRAITS.iter().find(|(it, _)| name.as_str() == Some(*it))?;
+    db.lang_item(krate, lang_item)?.as_trait()
+}
+
+fn resolve_trait_item(
+    db: &dyn HirDatabase,
+    trait_: TraitId,
+    name: &Name,
+    ns: Option<Namespace>,
+) -> Option<DocLinkDef> {
+    db.trait_data(trait_).items.iter().find(|it| it.0 == *name).and_then(|&(_, assoc_id)| {
+        as_module_def_if_namespace_matches(AssocItem::from(assoc_id), ns)
+    })
+}
+
 fn resolve_assoc_item(
     db: &dyn HirDatabase,
     ty: &Type,
@@ -305,7 +496,26 @@ fn as_module_def_if_namespace_matches(
     (ns.unwrap_or(expected_ns) == expected_ns).then(|| DocLinkDef::ModuleDef(def))
 }
 
-fn modpath_from_str(db: &dyn HirDatabase, link: &str) -> Option<ModPath> {
+fn modpath_from_str(
+    db: &dyn HirDatabase,
+    link: &str,
+    legacy_slash_separators: bool,
+) -> Option<ModPath> {
+    // Old rustdoc accepted `foo/bar` style links, translate them to regular paths.
+    let legacy_link;
+    let link = if legacy_slash_separators && link.contains('/') {
+        legacy_link = link.trim_end_matches('/').replace('/', "::");
+        &*legacy_link
+    } else {
+        link
+    };
+
+    // Reject text that can never be a path early, like rustdoc's synthetic `{{closure}}` names,
+    // instead of splicing it into the item we parse below.
+    if link.trim().is_empty() || link.contains(['{', '}', ';', '=']) {
+        return None;
+    }
+
     // FIXME: this is not how we should get a mod path here.
     let try_get_modpath = |link: &str| {
         let ast_path = ast::SourceFile::parse(&format!("type T = {link};"))
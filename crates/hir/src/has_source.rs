@@ -11,9 +11,9 @@ use hir_expand::{HirFileId, InFile};
 use syntax::ast;
 
 use crate::{
-    db::HirDatabase, Adt, Const, Enum, ExternCrateDecl, Field, FieldSource, Function, Impl,
-    LifetimeParam, LocalSource, Macro, Module, Static, Struct, Trait, TraitAlias, TypeAlias,
-    TypeOrConstParam, Union, Variant,
+    db::HirDatabase, Adt, CfgInactiveItem, Const, Enum, ExternCrateDecl, Field, FieldSource,
+    Function, Impl, LifetimeParam, LocalSource, Macro, Module, Static, Struct, Trait, TraitAlias,
+    TypeAlias, TypeOrConstParam, Union, Variant,
 };
 
 pub trait HasSource {
@@ -215,3 +215,11 @@ impl HasSource for ExternCrateDecl {
         Some(self.id.lookup(db.upcast()).source(db.upcast()))
     }
 }
+
+impl HasSource for CfgInactiveItem {
+    type Ast = ast::Item;
+
+    fn source(self, db: &dyn HirDatabase) -> Option<InFile<Self::Ast>> {
+        Some(self.id.source(db.upcast()))
+    }
+}
@@ -88,7 +88,11 @@ use triomphe::Arc;
 use crate::db::{DefDatabase, HirDatabase};
 
 pub use crate::{
-    attrs::{resolve_doc_path_on, HasAttrs},
+    attrs::{
+        doc_link_def_is_deprecated, resolve_doc_path_on, resolve_doc_path_prefix,
+        resolve_doc_path_with_crate_on, resolve_visible_doc_path_on, DocPathResolveOptions,
+        HasAttrs,
+    },
     diagnostics::*,
     has_source::HasSource,
     semantics::{
@@ -225,6 +229,10 @@ impl Crate {
         db.crate_graph()[self.id].version.clone()
     }
 
+    pub fn is_unstable_feature_enabled(self, db: &dyn HirDatabase, feature: &str) -> bool {
+        db.crate_def_map(self.id).is_unstable_feature_enabled(feature)
+    }
+
     pub fn display_name(self, db: &dyn HirDatabase) -> Option<CrateDisplayName> {
         db.crate_graph()[self.id].display_name.clone()
     }
@@ -5096,4 +5104,22 @@ pub enum DocLinkDef {
     ModuleDef(ModuleDef),
     Field(Field),
     SelfType(Trait),
+    /// Only produced when resolving with [`DocPathResolveOptions::include_cfg_inactive`].
+    CfgInactive(CfgInactiveItem),
+}
+
+/// An item that is disabled by a `#[cfg]`, and therefore has no definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CfgInactiveItem {
+    pub(crate) id: hir_def::CfgInactiveItem,
+}
+
+impl CfgInactiveItem {
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.id.name(db.upcast())
+    }
+
+    pub fn module(self) -> Module {
+        Module { id: self.id.module }
+    }
 }
@@ -1,7 +1,9 @@
 //! This module defines an accumulator for completions which are going to be presented to user.
 
 pub(crate) mod attribute;
+pub(crate) mod doc_link;
 pub(crate) mod dot;
+pub(crate) mod escape;
 pub(crate) mod expr;
 pub(crate) mod extern_abi;
 pub(crate) mod field;
@@ -11,6 +13,7 @@ pub(crate) mod format_string;
 pub(crate) mod item_list;
 pub(crate) mod keyword;
 pub(crate) mod lifetime;
+pub(crate) mod literal_suffix;
 pub(crate) mod mod_;
 pub(crate) mod pattern;
 pub(crate) mod postfix;
@@ -25,7 +28,10 @@ pub(crate) mod extern_crate;
 use std::iter;
 
 use hir::{known, HasAttrs, ScopeDef, Variant};
-use ide_db::{imports::import_assets::LocatedImport, RootDatabase, SymbolKind};
+use ide_db::{
+    documentation::HasDocs, imports::import_assets::LocatedImport, RootDatabase, SnippetCap,
+    SymbolKind,
+};
 use syntax::ast;
 
 use crate::{
@@ -33,19 +39,20 @@ use crate::{
         DotAccess, ItemListKind, NameContext, NameKind, NameRefContext, NameRefKind,
         PathCompletionCtx, PathKind, PatternContext, TypeLocation, Visible,
     },
-    item::Builder,
+    item::{Builder, CompletionRelevanceTypeMatch},
     render::{
         const_::render_const,
         function::{render_fn, render_method},
         literal::{render_struct_literal, render_variant_lit},
         macro_::render_macro,
         pattern::{render_struct_pat, render_variant_pat},
-        render_field, render_path_resolution, render_pattern_resolution, render_tuple_field,
+        render_field, render_field_shorthand, render_path_resolution, render_pattern_resolution,
+        render_tuple_field,
         type_alias::{render_type_alias, render_type_alias_with_eq},
         union_literal::render_union_literal,
         RenderContext,
     },
-    CompletionContext, CompletionItem, CompletionItemKind,
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionRelevance,
 };
 
 /// Represents an in-progress set of completions being built.
@@ -79,6 +86,35 @@ impl Completions {
         }
     }
 
+    /// Renders the collected completions one per line as `kind label detail [score]`, in the order
+    /// they were added.
+    #[cfg(test)]
+    pub(crate) fn debug_render(&self) -> String {
+        self.buf
+            .iter()
+            .map(|it| {
+                let mut line = format!("{} {}", it.kind.tag(), it.label);
+                if let Some(label_detail) = &it.label_detail {
+                    line.push_str(label_detail);
+                }
+                if let Some(detail) = &it.detail {
+                    line.push(' ');
+                    line.push_str(detail);
+                }
+                format!("{line} [{}]\n", it.relevance.score())
+            })
+            .collect()
+    }
+
+    /// Stably sorts the collected completions with the given comparator.
+    #[cfg(test)]
+    pub(crate) fn sort_by(
+        &mut self,
+        cmp: impl FnMut(&CompletionItem, &CompletionItem) -> std::cmp::Ordering,
+    ) {
+        self.buf.sort_by(cmp)
+    }
+
     pub(crate) fn add_keyword(&mut self, ctx: &CompletionContext<'_>, keyword: &'static str) {
         let item = CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), keyword);
         item.add_to(self, ctx.db);
@@ -121,6 +157,16 @@ impl Completions {
     ) {
         let mut item = CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), kw);
 
+        // rank bool literals as type matches when a `bool` is expected
+        if matches!(kw, "true" | "false")
+            && ctx.expected_type.as_ref().map_or(false, |ty| ty.is_bool())
+        {
+            item.set_relevance(CompletionRelevance {
+                type_match: Some(CompletionRelevanceTypeMatch::Exact),
+                ..Default::default()
+            });
+        }
+
         match ctx.config.snippet_cap {
             Some(cap) => {
                 if incomplete_let && snippet.ends_with('}') {
@@ -153,6 +199,19 @@ impl Completions {
         item.add_to(self, ctx.db);
     }
 
+    pub(crate) fn add_const_block(&mut self, ctx: &CompletionContext<'_>, cap: SnippetCap) {
+        let mut item =
+            CompletionItem::new(CompletionItemKind::Snippet, ctx.source_range(), "const");
+        item.insert_snippet(cap, "const { $0 }");
+        item.add_to(self, ctx.db);
+    }
+
+    pub(crate) fn add_const_arg_block(&mut self, ctx: &CompletionContext<'_>, cap: SnippetCap) {
+        let mut item = CompletionItem::new(CompletionItemKind::Snippet, ctx.source_range(), "{…}");
+        item.insert_snippet(cap, "{ $0 }");
+        item.add_to(self, ctx.db);
+    }
+
     pub(crate) fn add_crate_roots(
         &mut self,
         ctx: &CompletionContext<'_>,
@@ -177,6 +236,9 @@ impl Completions {
         if !ctx.check_stability(resolution.attrs(ctx.db).as_deref()) {
             return;
         }
+        if !ctx.could_match_typed_name(&local_name.to_smol_str(), &doc_aliases) {
+            return;
+        }
         let is_private_editable = match ctx.def_is_visible(&resolution) {
             Visible::Yes => false,
             Visible::Editable => true,
@@ -255,6 +317,21 @@ impl Completions {
         );
     }
 
+    pub(crate) fn add_extern_crate(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        name: hir::Name,
+        module: hir::Module,
+    ) {
+        let mut item = CompletionItem::new(
+            CompletionItemKind::SymbolKind(SymbolKind::Module),
+            ctx.source_range(),
+            name.to_smol_str(),
+        );
+        item.set_documentation(module.docs(ctx.db));
+        item.add_to(self, ctx.db);
+    }
+
     pub(crate) fn add_macro(
         &mut self,
         ctx: &CompletionContext<'_>,
@@ -265,6 +342,9 @@ impl Completions {
         if !ctx.check_stability(Some(&mac.attrs(ctx.db))) {
             return;
         }
+        if !ctx.could_match_typed_name(&local_name.to_smol_str(), &[]) {
+            return;
+        }
         let is_private_editable = match ctx.is_visible(&mac) {
             Visible::Yes => false,
             Visible::Editable => true,
@@ -291,12 +371,16 @@ impl Completions {
         if !ctx.check_stability(Some(&func.attrs(ctx.db))) {
             return;
         }
+        let doc_aliases = ctx.doc_aliases(&func);
+        let name = local_name.clone().unwrap_or_else(|| func.name(ctx.db));
+        if !ctx.could_match_typed_name(&name.to_smol_str(), &doc_aliases) {
+            return;
+        }
         let is_private_editable = match ctx.is_visible(&func) {
             Visible::Yes => false,
             Visible::Editable => true,
             Visible::No => return,
         };
-        let doc_aliases = ctx.doc_aliases(&func);
         self.add(
             render_fn(
                 RenderContext::new(ctx)
@@ -321,12 +405,16 @@ impl Completions {
         if !ctx.check_stability(Some(&func.attrs(ctx.db))) {
             return;
         }
+        let doc_aliases = ctx.doc_aliases(&func);
+        let name = local_name.clone().unwrap_or_else(|| func.name(ctx.db));
+        if !ctx.could_match_typed_name(&name.to_smol_str(), &doc_aliases) {
+            return;
+        }
         let is_private_editable = match ctx.is_visible(&func) {
             Visible::Yes => false,
             Visible::Editable => true,
             Visible::No => return,
         };
-        let doc_aliases = ctx.doc_aliases(&func);
         self.add(
             render_method(
                 RenderContext::new(ctx)
@@ -464,26 +552,55 @@ impl Completions {
         receiver: Option<hir::Name>,
         field: hir::Field,
         ty: &hir::Type,
+        via_deref: bool,
     ) {
         if !ctx.check_stability(Some(&field.attrs(ctx.db))) {
             return;
         }
+        let doc_aliases = ctx.doc_aliases(&field);
+        if !ctx.could_match_typed_name(&field.name(ctx.db).to_smol_str(), &doc_aliases) {
+            return;
+        }
         let is_private_editable = match ctx.is_visible(&field) {
             Visible::Yes => false,
             Visible::Editable => true,
             Visible::No => return,
         };
-        let doc_aliases = ctx.doc_aliases(&field);
         let item = render_field(
             RenderContext::new(ctx).private_editable(is_private_editable).doc_aliases(doc_aliases),
             dot_access,
             receiver,
             field,
             ty,
+            via_deref,
         );
         self.add(item);
     }
 
+    pub(crate) fn add_field_shorthand(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        field: hir::Field,
+        ty: &hir::Type,
+        local: hir::Local,
+    ) {
+        if !ctx.check_stability(Some(&field.attrs(ctx.db))) {
+            return;
+        }
+        let is_private_editable = match ctx.is_visible(&field) {
+            Visible::Yes => false,
+            Visible::Editable => true,
+            Visible::No => return,
+        };
+        let items = render_field_shorthand(
+            RenderContext::new(ctx).private_editable(is_private_editable),
+            field,
+            ty,
+            local,
+        );
+        items.into_iter().for_each(|item| self.add(item));
+    }
+
     pub(crate) fn add_struct_literal(
         &mut self,
         ctx: &CompletionContext<'_>,
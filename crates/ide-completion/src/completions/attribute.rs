@@ -2,6 +2,8 @@
 //!
 //! This module uses a bit of static metadata to provide completions for builtin-in attributes and lints.
 
+use std::iter;
+
 use ide_db::{
     generated::lints::{
         Lint, CLIPPY_LINTS, CLIPPY_LINT_GROUPS, DEFAULT_LINTS, FEATURES, RUSTDOC_LINTS,
@@ -18,7 +20,7 @@ use syntax::{
 
 use crate::{
     context::{AttrCtx, CompletionContext, PathCompletionCtx, Qualified},
-    item::CompletionItem,
+    item::{CompletionItem, CompletionRelevance},
     Completions,
 };
 
@@ -68,6 +70,25 @@ pub(crate) fn complete_known_attribute_input(
             lint::complete_lint(acc, ctx, colon_prefix, &existing_lints, &lints);
         }
         "cfg" => cfg::complete_cfg(acc, ctx),
+        "cfg_attr" => {
+            // `cfg_attr(predicate, attr)`, nested token trees belong to the attribute itself
+            let token = tt.syntax().token_at_offset(ctx.position.offset).left_biased()?;
+            if token.parent().as_ref() != Some(tt.syntax()) {
+                return None;
+            }
+            let in_predicate = !tt
+                .syntax()
+                .children_with_tokens()
+                .any(|it| it.kind() == T![,] && it.text_range().end() <= ctx.position.offset);
+            if in_predicate {
+                cfg::complete_cfg(acc, ctx);
+            } else {
+                ATTRIBUTES
+                    .iter()
+                    .filter(|attr| !attr.prefer_inner)
+                    .for_each(|attr| add_attribute(acc, ctx, attr));
+            }
+        }
         "macro_use" => {
             macro_use::complete_macro_use(acc, ctx, extern_crate, &parse_tt_as_comma_sep_paths(tt)?)
         }
@@ -105,6 +126,13 @@ pub(crate) fn complete_attribute_path(
             }
             return;
         }
+        // tool attributes like `#[rustfmt::skip]`, the tool namespace does not resolve to a module
+        Qualified::With { path, resolution: None, .. } => {
+            if let Some(attrs) = tool_attributes(path) {
+                attrs.iter().for_each(|attr| add_attribute(acc, ctx, attr));
+                return;
+            }
+        }
         // fresh use tree with leading colon2, only show crate roots
         Qualified::Absolute => acc.add_crate_roots(ctx, path_ctx),
         // only show modules in a fresh UseTree
@@ -126,25 +154,18 @@ pub(crate) fn complete_attribute_path(
     let attributes = annotated_item_kind.and_then(|kind| {
         if ast::Expr::can_cast(kind) {
             Some(EXPR_ATTRIBUTES)
+        } else if kind == SyntaxKind::SOURCE_FILE && !ctx.module.is_crate_root() {
+            // Crate-level attributes like `feature` or `no_std` are only valid in the crate root,
+            // an out-of-line module accepts the same inner attributes as an inline one.
+            KIND_TO_ATTRIBUTES.get(&SyntaxKind::ITEM_LIST).copied()
         } else {
             KIND_TO_ATTRIBUTES.get(&kind).copied()
         }
     });
 
     let add_completion = |attr_completion: &AttrCompletion| {
-        let mut item =
-            CompletionItem::new(SymbolKind::Attribute, ctx.source_range(), attr_completion.label);
-
-        if let Some(lookup) = attr_completion.lookup {
-            item.lookup_by(lookup);
-        }
-
-        if let Some((snippet, cap)) = attr_completion.snippet.zip(ctx.config.snippet_cap) {
-            item.insert_snippet(cap, snippet);
-        }
-
         if is_inner || !attr_completion.prefer_inner {
-            item.add_to(acc, ctx.db);
+            add_attribute(acc, ctx, attr_completion);
         }
     };
 
@@ -157,6 +178,58 @@ pub(crate) fn complete_attribute_path(
         None if is_inner => ATTRIBUTES.iter().for_each(add_completion),
         None => ATTRIBUTES.iter().filter(|compl| !compl.prefer_inner).for_each(add_completion),
     }
+
+    // `#[bench]` is unstable and only meaningful for benchmark functions in test modules.
+    if !is_inner
+        && annotated_item_kind == Some(SyntaxKind::FN)
+        && ctx.krate.is_unstable_feature_enabled(ctx.db, "test")
+        && is_in_test_module(ctx)
+    {
+        add_attribute(acc, ctx, &attr("bench", None, None));
+    }
+}
+
+fn tool_attributes(tool: &ast::Path) -> Option<&'static [AttrCompletion]> {
+    let tool = tool.as_single_name_ref()?;
+    TOOL_ATTRIBUTES.iter().find(|&&(name, _)| tool.text() == name).map(|&(_, attrs)| attrs)
+}
+
+fn add_attribute(acc: &mut Completions, ctx: &CompletionContext<'_>, attr: &AttrCompletion) {
+    let mut item = CompletionItem::new(SymbolKind::Attribute, ctx.source_range(), attr.label);
+
+    if let Some(lookup) = attr.lookup {
+        item.lookup_by(lookup);
+    }
+
+    if let Some((snippet, cap)) = attr.snippet.zip(ctx.config.snippet_cap) {
+        item.insert_snippet(cap, snippet);
+    }
+
+    if TEST_ATTRIBUTES.contains(&attr.key()) && is_in_test_module(ctx) {
+        item.set_relevance(CompletionRelevance { is_test_attribute: true, ..Default::default() });
+    }
+
+    item.add_to(acc, ctx.db);
+}
+
+/// Attributes that are ranked higher when completing inside of a test module.
+const TEST_ATTRIBUTES: &[&str] = &["bench", "ignore", "should_panic", "test"];
+
+/// Whether the completion happens inside of a `#[cfg(test)]` module, either inline or out-of-line.
+fn is_in_test_module(ctx: &CompletionContext<'_>) -> bool {
+    let is_cfg_test = |module: &ast::Module| {
+        module.attrs().any(|attr| match attr.as_simple_call() {
+            Some((name, tt)) if name == "cfg" => tt
+                .syntax()
+                .children_with_tokens()
+                .any(|it| it.kind() == SyntaxKind::IDENT && it.to_string() == "test"),
+            _ => false,
+        })
+    };
+    ctx.token.parent_ancestors().filter_map(ast::Module::cast).any(|it| is_cfg_test(&it))
+        || iter::successors(Some(ctx.module), |it| it.parent(ctx.db))
+            .filter_map(|it| it.declaration_source(ctx.db))
+            .any(|it| is_cfg_test(&it.value))
 }
 
 struct AttrCompletion {
@@ -243,13 +316,13 @@ static KIND_TO_ATTRIBUTES: Lazy<FxHashMap<SyntaxKind, &[&str]>> = Lazy::new(|| {
             FN,
             attrs!(
                 item, linkable,
-                "cold", "ignore", "inline", "must_use", "panic_handler", "proc_macro",
+                "cold", "ignore", "inline", "panic_handler", "proc_macro",
                 "proc_macro_derive", "proc_macro_attribute", "should_panic", "target_feature",
                 "test", "track_caller"
             ),
         ),
         (STATIC, attrs!(item, linkable, "global_allocator", "used")),
-        (TRAIT, attrs!(item, "must_use")),
+        (TRAIT, attrs!(item)),
         (IMPL, attrs!(item, "automatically_derived")),
         (ASSOC_ITEM_LIST, attrs!(item)),
         (EXTERN_BLOCK, attrs!(item, "link")),
@@ -351,6 +424,37 @@ const ATTRIBUTES: &[AttrCompletion] = &[
     .prefer_inner(),
 ];
 
+/// Attributes of the tool namespaces known to rustc, <https://doc.rust-lang.org/reference/attributes.html#tool-attributes>
+const TOOL_ATTRIBUTES: &[(&str, &[AttrCompletion])] = &[
+    (
+        "clippy",
+        &[
+            attr("author", None, None),
+            attr(
+                r#"cognitive_complexity = "…""#,
+                Some("cognitive_complexity"),
+                Some(r#"cognitive_complexity = "${0:25}""#),
+            ),
+            attr("dump", None, None),
+            attr("format_args", None, None),
+            attr("has_significant_drop", None, None),
+            attr(r#"msrv = "…""#, Some("msrv"), Some(r#"msrv = "${0:version}""#)),
+        ],
+    ),
+    (
+        "rustfmt",
+        &[
+            attr("skip", None, None),
+            attr(
+                "skip::attributes(…)",
+                Some("skip::attributes"),
+                Some("skip::attributes(${0:attr})"),
+            ),
+            attr("skip::macros(…)", Some("skip::macros"), Some("skip::macros(${0:macro})")),
+        ],
+    ),
+];
+
 fn parse_comma_sep_expr(input: ast::TokenTree) -> Option<Vec<ast::Expr>> {
     let r_paren = input.r_paren_token()?;
     let tokens = input
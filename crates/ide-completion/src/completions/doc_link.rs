@@ -0,0 +1,133 @@
+//! Completes item names in intra-doc links, like ``/// [`Fo$0``.
+
+use hir::ScopeDef;
+use syntax::{ast, AstToken, TextRange, TextSize};
+
+use crate::{context::CompletionContext, render::res_to_kind, CompletionItem, Completions};
+
+/// Complete the names of the items in scope after an unclosed `[` in a doc comment.
+pub(crate) fn complete_doc_link(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    comment: &ast::Comment,
+) -> Option<()> {
+    let cursor = ctx.position.offset;
+    let comment_start = comment.syntax().text_range().start();
+    let prefix = comment.text().get(..(cursor - comment_start).into())?;
+    let (_, link) = prefix.rsplit_once('[')?;
+    let typed = link.strip_prefix('`').unwrap_or(link);
+    if !typed.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    let source_range = TextRange::new(cursor - TextSize::of(typed), cursor);
+    ctx.process_all_names(&mut |name, def, doc_aliases| {
+        if !matches!(def, ScopeDef::ModuleDef(_)) {
+            return;
+        }
+        let mut item =
+            CompletionItem::new(res_to_kind(def), source_range, name.unescaped().to_smol_str());
+        item.doc_aliases(doc_aliases);
+        item.add_to(acc, ctx.db);
+    });
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::tests::{check_edit, completion_list};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn completes_items_in_scope() {
+        check(
+            r#"
+struct Foo;
+fn bar() {}
+mod baz {}
+
+/// See [$0
+fn documented() {}
+"#,
+            expect![[r#"
+                fn bar
+                fn documented
+                md baz
+                st Foo
+                bt u32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completions_outside_of_links() {
+        check(
+            r#"
+struct Foo;
+
+/// See Fo$0
+fn documented() {}
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+struct Foo;
+
+/// See [Foo] and Fo$0
+fn documented() {}
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+struct Foo;
+
+// See [Fo$0
+fn documented() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn replaces_typed_name() {
+        check_edit(
+            "Foo",
+            r#"
+struct Foo;
+
+/// See [Fo$0
+fn documented() {}
+"#,
+            r#"
+struct Foo;
+
+/// See [Foo
+fn documented() {}
+"#,
+        );
+        check_edit(
+            "Foo",
+            r#"
+struct Foo;
+
+/// See [`Fo$0
+fn documented() {}
+"#,
+            r#"
+struct Foo;
+
+/// See [`Foo
+fn documented() {}
+"#,
+        );
+    }
+}
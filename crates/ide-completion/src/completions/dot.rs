@@ -1,5 +1,6 @@
 //! Completes references after dot (fields and method calls).
 
+use hir::AsAssocItem;
 use ide_db::FxHashSet;
 
 use crate::{
@@ -19,7 +20,7 @@ pub(crate) fn complete_dot(
     };
 
     // Suggest .await syntax for types that implement Future trait
-    if receiver_ty.impls_into_future(ctx.db) {
+    if ctx.is_in_async_context() && receiver_ty.impls_into_future(ctx.db) {
         let mut item =
             CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), "await");
         item.detail("expr.await");
@@ -34,7 +35,7 @@ pub(crate) fn complete_dot(
         acc,
         ctx,
         receiver_ty,
-        |acc, field, ty| acc.add_field(ctx, dot_access, None, field, &ty),
+        |acc, field, ty, via_deref| acc.add_field(ctx, dot_access, None, field, &ty, via_deref),
         |acc, field, ty| acc.add_tuple_field(ctx, None, field, &ty),
         is_field_access,
         is_method_acces_with_parens,
@@ -71,7 +72,7 @@ pub(crate) fn complete_undotted_self(
         acc,
         ctx,
         &ty,
-        |acc, field, ty| {
+        |acc, field, ty, via_deref| {
             acc.add_field(
                 ctx,
                 &DotAccess {
@@ -82,6 +83,7 @@ pub(crate) fn complete_undotted_self(
                 Some(hir::known::SELF_PARAM),
                 field,
                 &ty,
+                via_deref,
             )
         },
         |acc, field, ty| acc.add_tuple_field(ctx, Some(hir::known::SELF_PARAM), field, &ty),
@@ -107,19 +109,22 @@ fn complete_fields(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
     receiver: &hir::Type,
-    mut named_field: impl FnMut(&mut Completions, hir::Field, hir::Type),
+    mut named_field: impl FnMut(&mut Completions, hir::Field, hir::Type, bool),
     mut tuple_index: impl FnMut(&mut Completions, usize, hir::Type),
     is_field_access: bool,
     is_method_acess_with_parens: bool,
 ) {
     let mut seen_names = FxHashSet::default();
+    let original = receiver.strip_references();
     for receiver in receiver.autoderef(ctx.db) {
+        // Fields of the receiver itself, as opposed to the types it derefs to.
+        let is_own_field = receiver.strip_references() == original;
         for (field, ty) in receiver.fields(ctx.db) {
             if seen_names.insert(field.name(ctx.db))
                 && (is_field_access
                     || (is_method_acess_with_parens && (ty.is_fn() || ty.is_closure())))
             {
-                named_field(acc, field, ty);
+                named_field(acc, field, ty, !is_own_field);
             }
         }
         for (i, ty) in receiver.tuple_fields(ctx.db).into_iter().enumerate() {
@@ -142,6 +147,22 @@ fn complete_methods(
     mut f: impl FnMut(hir::Function),
 ) {
     let mut seen_methods = FxHashSet::default();
+    // Generic methods of a trait object's traits can't be called through the trait object. Only
+    // look at the receiver itself, smart pointers like `Box<dyn Trait>` may implement the trait
+    // through blanket impls that do allow these calls.
+    let receiver_without_refs = receiver.strip_references();
+    let dyn_traits: FxHashSet<_> = match receiver_without_refs.as_dyn_trait() {
+        Some(_) => receiver_without_refs.applicable_inherent_traits(ctx.db).collect(),
+        None => FxHashSet::default(),
+    };
+    let is_uncallable_on_dyn = |func: hir::Function| {
+        !dyn_traits.is_empty()
+            && func
+                .as_assoc_item(ctx.db)
+                .and_then(|it| it.containing_trait(ctx.db))
+                .map_or(false, |trait_| dyn_traits.contains(&trait_))
+            && !hir::GenericDef::from(func).type_params(ctx.db).is_empty()
+    };
     receiver.iterate_method_candidates_with_traits(
         ctx.db,
         &ctx.scope,
@@ -149,7 +170,10 @@ fn complete_methods(
         Some(ctx.module),
         None,
         |func| {
-            if func.self_param(ctx.db).is_some() && seen_methods.insert(func.name(ctx.db)) {
+            if func.self_param(ctx.db).is_some()
+                && !is_uncallable_on_dyn(func)
+                && seen_methods.insert(func.name(ctx.db))
+            {
                 f(func);
             }
             None::<()>
@@ -175,6 +199,80 @@ mod tests {
         expect.assert_eq(&actual);
     }
 
+    #[test]
+    fn completes_methods_on_self_returning_chain() {
+        check(
+            r#"
+struct Builder { x: u32 }
+impl Builder {
+    fn new() -> Builder { Builder { x: 0 } }
+    fn step(self) -> Self { self }
+    fn build(self) -> u32 { self.x }
+}
+fn main() { Builder::new().step().$0 }
+"#,
+            expect![[r#"
+                fd x       u32
+                me build() fn(self) -> u32
+                me step()  fn(self) -> Builder
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_methods_of_captured_variable_in_closure() {
+        check(
+            r#"
+struct String;
+impl String {
+    fn len(&self) -> usize { 0 }
+    fn push_str(&mut self, s: &str) {}
+}
+fn main() {
+    let outer = String;
+    let f = |_: u32| outer.$0;
+}
+"#,
+            expect![[r#"
+                me len()       fn(&self) -> usize
+                me push_str(…) fn(&mut self, &str)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_object_safe_methods_on_trait_object() {
+        let actual = completion_list_no_kw(
+            r#"
+//- minicore: iterators
+fn foo(it: &dyn Iterator<Item = u8>) {
+    it.$0
+}
+"#,
+        );
+        assert!(actual.contains("me next()"), "{actual}");
+        assert!(!actual.contains("filter_map"), "{actual}");
+    }
+
+    #[test]
+    fn completes_generic_methods_on_boxed_trait_object() {
+        let actual = completion_list_no_kw(
+            r#"
+//- minicore: iterators
+struct Box<T: ?Sized>(T);
+impl<I: Iterator + ?Sized> Iterator for Box<I> {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> { loop {} }
+}
+fn foo(it: Box<dyn Iterator<Item = u8>>) {
+    it.$0
+}
+"#,
+        );
+        assert!(actual.contains("me next()"), "{actual}");
+        assert!(actual.contains("me filter_map(…)"), "{actual}");
+    }
+
     #[test]
     fn test_struct_field_and_method_completion() {
         check(
@@ -706,13 +804,36 @@ fn test(a: A) {
 }
 "#,
             expect![[r#"
-                fd another                u32
+                fd another (via B)        u32
                 fd field                  u8
                 me deref() (use core::ops::Deref) fn(&self) -> &<Self as Deref>::Target
             "#]],
         );
     }
 
+    #[test]
+    fn test_field_via_deref_labeled_with_owner() {
+        check(
+            r#"
+//- minicore: deref
+struct Inner { inner_field: u32 }
+struct Outer { outer_field: u8 }
+impl core::ops::Deref for Outer {
+    type Target = Inner;
+    fn deref(&self) -> &Self::Target { loop {} }
+}
+fn test(o: &Outer) {
+    o.$0
+}
+"#,
+            expect![[r#"
+                fd inner_field (via Inner) u32
+                fd outer_field            u8
+                me deref() (use core::ops::Deref) fn(&self) -> &<Self as Deref>::Target
+            "#]],
+        );
+    }
+
     #[test]
     fn test_tuple_field_no_same_index() {
         check(
@@ -730,7 +851,7 @@ fn test(a: A) {
 "#,
             expect![[r#"
                 fd 0                      u8
-                fd 1                      u32
+                fd 1 (via B)              u32
                 me deref() (use core::ops::Deref) fn(&self) -> &<Self as Deref>::Target
             "#]],
         );
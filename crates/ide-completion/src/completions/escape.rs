@@ -0,0 +1,150 @@
+//! Completes escape sequences in string and char literals.
+
+use syntax::{
+    ast::{self, IsString},
+    AstToken, SyntaxToken, TextRange, TextSize,
+};
+
+use crate::{context::CompletionContext, CompletionItem, CompletionItemKind, Completions};
+
+const ESCAPES: &[(&str, &str)] = &[
+    (r"\n", "line feed"),
+    (r"\r", "carriage return"),
+    (r"\t", "tab"),
+    (r"\\", "backslash"),
+    (r"\0", "null"),
+    (r"\'", "single quote"),
+    (r#"\""#, "double quote"),
+];
+
+/// Complete escape sequences after a `\` in a (non-raw) string or char literal.
+pub(crate) fn complete_escape_sequences(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    original: &SyntaxToken,
+) -> Option<()> {
+    if ast::String::cast(original.clone()).map_or(false, |it| it.is_raw()) {
+        return None;
+    }
+    let cursor = ctx.position.offset;
+    let lit_start = original.syntax().text_range().start();
+    let prefix = original.text().get(..(cursor - lit_start).into())?;
+    // an even number of trailing backslashes is a sequence of escaped backslashes
+    let backslashes = prefix.chars().rev().take_while(|&c| c == '\\').count();
+    if backslashes % 2 == 0 {
+        return None;
+    }
+
+    let source_range = TextRange::new(cursor - TextSize::of('\\'), cursor);
+    for &(escape, detail) in ESCAPES {
+        let mut item = CompletionItem::new(CompletionItemKind::Snippet, source_range, escape);
+        item.detail(detail);
+        item.add_to(acc, ctx.db);
+    }
+
+    let mut item = CompletionItem::new(CompletionItemKind::Snippet, source_range, r"\u{…}");
+    item.detail("unicode escape").lookup_by(r"\u");
+    match ctx.config.snippet_cap {
+        Some(cap) => item.insert_snippet(cap, r"\u{$0}"),
+        None => item.insert_text(r"\u{}"),
+    };
+    item.add_to(acc, ctx.db);
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::tests::{check_edit, completion_list};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn completes_after_backslash() {
+        check(
+            r#"
+fn main() {
+    let _ = "foo\$0 bar";
+}
+"#,
+            expect![[r#"
+                sn \"    double quote
+                sn \'    single quote
+                sn \0    null
+                sn \\    backslash
+                sn \n    line feed
+                sn \r    carriage return
+                sn \t    tab
+                sn \u{…} unicode escape
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_in_char_literal() {
+        check(
+            r#"
+fn main() {
+    let _ = '\$0';
+}
+"#,
+            expect![[r#"
+                sn \"    double quote
+                sn \'    single quote
+                sn \0    null
+                sn \\    backslash
+                sn \n    line feed
+                sn \r    carriage return
+                sn \t    tab
+                sn \u{…} unicode escape
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completions_after_escaped_backslash() {
+        check(
+            r#"
+fn main() {
+    let _ = "foo\\$0";
+}
+"#,
+            expect![""],
+        );
+    }
+
+    #[test]
+    fn unicode_escape_snippet() {
+        check_edit(
+            r"\u",
+            r#"
+fn main() {
+    let _ = "foo\$0 bar";
+}
+"#,
+            r#"
+fn main() {
+    let _ = "foo\u{$0} bar";
+}
+"#,
+        );
+        check_edit(
+            r"\u",
+            r#"
+fn main() {
+    let _ = '\$0';
+}
+"#,
+            r#"
+fn main() {
+    let _ = '\u{$0}';
+}
+"#,
+        );
+    }
+}
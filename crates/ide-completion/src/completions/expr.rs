@@ -1,12 +1,14 @@
 //! Completion of names from the current scope in expression position.
 
 use hir::ScopeDef;
+use ide_db::{base_db::Edition, SymbolKind};
 use syntax::ast;
 
 use crate::{
     completions::record::add_default_update,
     context::{ExprCtx, PathCompletionCtx, Qualified},
-    CompletionContext, Completions,
+    item::CompletionRelevanceTypeMatch,
+    CompletionContext, CompletionItem, CompletionRelevance, Completions,
 };
 
 pub(crate) fn complete_expr_path(
@@ -24,6 +26,7 @@ pub(crate) fn complete_expr_path(
         in_block_expr,
         in_loop_body,
         after_if_expr,
+        after_refutable_let,
         in_condition,
         incomplete_let,
         ref ref_expr_parent,
@@ -219,6 +222,7 @@ pub(crate) fn complete_expr_path(
                         );
                     }
                 }
+                add_default_call(acc, ctx, adt);
             }
             ctx.process_all_names(&mut |name, def, doc_aliases| match def {
                 ScopeDef::ModuleDef(hir::ModuleDef::Trait(t)) => {
@@ -262,6 +266,11 @@ pub(crate) fn complete_expr_path(
 
                     if !in_block_expr {
                         add_keyword("unsafe", "unsafe {\n    $0\n}");
+                        add_keyword("async", "async {\n    $0\n}");
+                        add_keyword("async move", "async move {\n    $0\n}");
+                        if gen_blocks_enabled(ctx) {
+                            add_keyword("gen", "gen {\n    $0\n}");
+                        }
                     }
                     add_keyword("match", "match $1 {\n    $0\n}");
                     add_keyword("while", "while $1 {\n    $0\n}");
@@ -276,6 +285,9 @@ pub(crate) fn complete_expr_path(
                     add_keyword("for", "for $1 in $2 {\n    $0\n}");
                     add_keyword("true", "true");
                     add_keyword("false", "false");
+                    if ctx.krate.is_unstable_feature_enabled(ctx.db, "yeet_expr") {
+                        add_keyword("do yeet", "do yeet $0");
+                    }
 
                     if in_condition || in_block_expr {
                         add_keyword("let", "let");
@@ -286,6 +298,11 @@ pub(crate) fn complete_expr_path(
                         add_keyword("else if", "else if $1 {\n    $0\n}");
                     }
 
+                    if after_refutable_let {
+                        add_keyword("else", "else {\n    $0\n};");
+                        add_keyword("else return", "else {\n    return$0;\n};");
+                    }
+
                     if wants_mut_token {
                         add_keyword("mut", "mut ");
                     }
@@ -328,3 +345,35 @@ pub(crate) fn complete_expr_path(
         }
     }
 }
+
+/// Offers `Type::default()` when the expected type implements `Default`.
+fn add_default_call(acc: &mut Completions, ctx: &CompletionContext<'_>, adt: hir::Adt) {
+    let Some(default_trait) = ctx.famous_defs().core_default_Default() else { return };
+    // `Foo::default()` does not fit where a `&Foo` is expected.
+    let Some(ty) = ctx.expected_type.as_ref().filter(|ty| !ty.is_reference()) else { return };
+    if !ty.impls_trait(ctx.db, default_trait, &[]) {
+        return;
+    }
+    let Some(path) = ctx.module.find_use_path(
+        ctx.db,
+        hir::ModuleDef::from(adt),
+        ctx.config.prefer_no_std,
+        ctx.config.prefer_prelude,
+    ) else {
+        return;
+    };
+
+    let label = format!("{}::default()", path.display(ctx.db));
+    let mut item = CompletionItem::new(SymbolKind::Function, ctx.source_range(), label);
+    item.set_relevance(CompletionRelevance {
+        type_match: Some(CompletionRelevanceTypeMatch::Exact),
+        ..Default::default()
+    });
+    item.add_to(acc, ctx.db);
+}
+
+/// `gen` blocks are only available on edition 2024 with the `gen_blocks` feature enabled.
+fn gen_blocks_enabled(ctx: &CompletionContext<'_>) -> bool {
+    ctx.krate.edition(ctx.db) >= Edition::Edition2024
+        && ctx.krate.is_unstable_feature_enabled(ctx.db, "gen_blocks")
+}
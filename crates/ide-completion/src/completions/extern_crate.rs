@@ -1,9 +1,8 @@
 //! Completion for extern crates
 
 use hir::Name;
-use ide_db::{documentation::HasDocs, SymbolKind};
 
-use crate::{context::CompletionContext, CompletionItem, CompletionItemKind};
+use crate::context::CompletionContext;
 
 use super::Completions;
 
@@ -14,15 +13,7 @@ pub(crate) fn complete_extern_crate(acc: &mut Completions, ctx: &CompletionConte
         if imported_extern_crates.contains(&name) {
             continue;
         }
-
-        let mut item = CompletionItem::new(
-            CompletionItemKind::SymbolKind(SymbolKind::Module),
-            ctx.source_range(),
-            name.to_smol_str(),
-        );
-        item.set_documentation(module.docs(ctx.db));
-
-        item.add_to(acc, ctx.db);
+        acc.add_extern_crate(ctx, name, module);
     }
 }
 
@@ -49,6 +40,22 @@ mod other_mod {}
         assert_eq!("md other_crate_a\n".to_string(), completion_list);
     }
 
+    #[test]
+    fn completes_dependencies_without_prefix() {
+        let case = r#"
+//- /lib.rs crate:dep_a
+// nothing here
+//- /lib.rs crate:dep_b
+// nothing here
+//- /lib.rs crate:lib deps:dep_a,dep_b
+extern crate $0
+"#;
+
+        let completion_list = completion_list_no_kw(case);
+
+        assert_eq!("md dep_a\nmd dep_b\n".to_string(), completion_list);
+    }
+
     #[test]
     fn will_not_complete_existing_import() {
         let case = r#"
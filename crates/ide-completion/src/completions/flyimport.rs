@@ -268,6 +268,7 @@ fn import_on_the_fly(
             let original_item = &import.original_item;
             !ctx.is_item_hidden(&import.item_to_import)
                 && !ctx.is_item_hidden(original_item)
+                && ctx.is_from_importable_crate(original_item)
                 && ctx.check_stability(original_item.attrs(ctx.db).as_deref())
         })
         .sorted_by(|a, b| {
@@ -320,6 +321,7 @@ fn import_on_the_fly_pat_(
             let original_item = &import.original_item;
             !ctx.is_item_hidden(&import.item_to_import)
                 && !ctx.is_item_hidden(original_item)
+                && ctx.is_from_importable_crate(original_item)
                 && ctx.check_stability(original_item.attrs(ctx.db).as_deref())
         })
         .sorted_by(|a, b| {
@@ -365,6 +367,7 @@ fn import_on_the_fly_method(
         .filter(|import| {
             !ctx.is_item_hidden(&import.item_to_import)
                 && !ctx.is_item_hidden(&import.original_item)
+                && ctx.is_from_importable_crate(&import.original_item)
         })
         .sorted_by(|a, b| {
             let key = |import_path| {
@@ -127,6 +127,7 @@ fn add_keywords(acc: &mut Completions, ctx: &CompletionContext<'_>, kind: Option
 
             add_keyword("fn", "fn $1($2) {\n    $0\n}");
             add_keyword("unsafe", "unsafe");
+            add_keyword("async", "async");
             add_keyword("const", "const $0");
         }
     }
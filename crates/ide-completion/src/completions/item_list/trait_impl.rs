@@ -36,8 +36,9 @@ use ide_db::{
     documentation::HasDocs, path_transform::PathTransform,
     syntax_helpers::insert_whitespace_into_node, traits::get_missing_assoc_items, SymbolKind,
 };
+use itertools::Itertools;
 use syntax::{
-    ast::{self, edit_in_place::AttrsOwnerEdit, HasTypeBounds},
+    ast::{self, edit::IndentLevel, edit_in_place::AttrsOwnerEdit, HasTypeBounds},
     AstNode, SyntaxElement, SyntaxKind, TextRange, T,
 };
 use text_edit::TextEdit;
@@ -150,24 +151,37 @@ fn complete_trait_impl(
     impl_def: &ast::Impl,
 ) {
     if let Some(hir_impl) = ctx.sema.to_def(impl_def) {
-        get_missing_assoc_items(&ctx.sema, impl_def)
+        let missing_items: Vec<_> = get_missing_assoc_items(&ctx.sema, impl_def)
             .into_iter()
             .filter(|item| ctx.check_stability(Some(&item.attrs(ctx.db))))
-            .for_each(|item| {
-                use self::ImplCompletionKind::*;
-                match (item, kind) {
-                    (hir::AssocItem::Function(func), All | Fn) => {
-                        add_function_impl(acc, ctx, replacement_range, func, hir_impl)
-                    }
-                    (hir::AssocItem::TypeAlias(type_alias), All | TypeAlias) => {
-                        add_type_alias_impl(acc, ctx, replacement_range, type_alias, hir_impl)
-                    }
-                    (hir::AssocItem::Const(const_), All | Const) => {
-                        add_const_impl(acc, ctx, replacement_range, const_, hir_impl)
-                    }
-                    _ => {}
+            .collect();
+        let missing_type_aliases: Vec<_> = missing_items
+            .iter()
+            .filter_map(|item| match item {
+                hir::AssocItem::TypeAlias(it) => Some(*it),
+                _ => None,
+            })
+            .collect();
+        missing_items.into_iter().for_each(|item| {
+            use self::ImplCompletionKind::*;
+            match (item, kind) {
+                (hir::AssocItem::Function(func), All | Fn) => add_function_impl(
+                    acc,
+                    ctx,
+                    replacement_range,
+                    func,
+                    hir_impl,
+                    &missing_type_aliases,
+                ),
+                (hir::AssocItem::TypeAlias(type_alias), All | TypeAlias) => {
+                    add_type_alias_impl(acc, ctx, replacement_range, type_alias, hir_impl)
+                }
+                (hir::AssocItem::Const(const_), All | Const) => {
+                    add_const_impl(acc, ctx, replacement_range, const_, hir_impl)
                 }
-            });
+                _ => {}
+            }
+        });
     }
 }
 
@@ -177,6 +191,7 @@ fn add_function_impl(
     replacement_range: TextRange,
     func: hir::Function,
     impl_def: hir::Impl,
+    missing_type_aliases: &[hir::TypeAlias],
 ) {
     let fn_name = func.name(ctx.db);
 
@@ -208,7 +223,29 @@ fn add_function_impl(
             let function_decl = function_declaration(&transformed_fn, source.file_id.is_macro());
             match ctx.config.snippet_cap {
                 Some(cap) => {
-                    let snippet = format!("{function_decl} {{\n    $0\n}}");
+                    // Scaffold the associated types the signature refers to, like the `Output`
+                    // of `Add::add`, as the function is unusable without them.
+                    let mut items: Vec<String> =
+                        referenced_type_aliases(ctx, &transformed_fn, missing_type_aliases)
+                            .into_iter()
+                            .filter_map(|type_alias| {
+                                transformed_type_alias_declaration(ctx, type_alias, impl_def)
+                            })
+                            .enumerate()
+                            .map(|(idx, decl)| format!("{decl}${};", idx + 1))
+                            .collect();
+                    items.push(format!("{function_decl} {{\n    $0\n}}"));
+                    // The first item is inserted at the cursor, the others start on fresh lines
+                    // and have to be indented like the item being completed.
+                    let indent = IndentLevel::from_token(&ctx.original_token);
+                    let snippet = items
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, it)| match idx {
+                            0 => it.clone(),
+                            _ => it.lines().map(|line| format!("{indent}{line}")).join("\n"),
+                        })
+                        .join("\n\n");
                     item.snippet_edit(cap, TextEdit::replace(replacement_range, snippet));
                 }
                 None => {
@@ -261,50 +298,83 @@ fn add_type_alias_impl(
         .set_documentation(type_alias.docs(ctx.db))
         .set_relevance(CompletionRelevance { is_item_from_trait: true, ..Default::default() });
 
-    if let Some(source) = ctx.sema.source(type_alias) {
-        let assoc_item = ast::AssocItem::TypeAlias(source.value);
-        if let Some(transformed_item) = get_transformed_assoc_item(ctx, assoc_item, impl_def) {
-            let transformed_ty = match transformed_item {
-                ast::AssocItem::TypeAlias(ty) => ty,
-                _ => unreachable!(),
-            };
+    if let Some(decl) = transformed_type_alias_declaration(ctx, type_alias, impl_def) {
+        match ctx.config.snippet_cap {
+            Some(cap) => {
+                let snippet = format!("{decl}$0;");
+                item.snippet_edit(cap, TextEdit::replace(replacement_range, snippet));
+            }
+            None => {
+                item.text_edit(TextEdit::replace(replacement_range, decl));
+            }
+        };
+        item.add_to(acc, ctx.db);
+    }
+}
 
-            let start = transformed_ty.syntax().text_range().start();
-
-            let end = if let Some(end) =
-                transformed_ty.colon_token().map(|tok| tok.text_range().start())
-            {
-                end
-            } else if let Some(end) = transformed_ty.eq_token().map(|tok| tok.text_range().start())
-            {
-                end
-            } else if let Some(end) =
-                transformed_ty.semicolon_token().map(|tok| tok.text_range().start())
-            {
-                end
-            } else {
-                return;
-            };
+/// Renders the declaration of `type_alias` as it should appear in `impl_def`, up to and
+/// including the `= `.
+fn transformed_type_alias_declaration(
+    ctx: &CompletionContext<'_>,
+    type_alias: hir::TypeAlias,
+    impl_def: hir::Impl,
+) -> Option<String> {
+    let source = ctx.sema.source(type_alias)?;
+    let assoc_item = ast::AssocItem::TypeAlias(source.value);
+    let transformed_ty = match get_transformed_assoc_item(ctx, assoc_item, impl_def)? {
+        ast::AssocItem::TypeAlias(ty) => ty,
+        _ => unreachable!(),
+    };
 
-            let len = end - start;
-            let mut decl = transformed_ty.syntax().text().slice(..len).to_string();
-            if !decl.ends_with(' ') {
-                decl.push(' ');
-            }
-            decl.push_str("= ");
+    let start = transformed_ty.syntax().text_range().start();
+    let end = transformed_ty
+        .colon_token()
+        .or_else(|| transformed_ty.eq_token())
+        .or_else(|| transformed_ty.semicolon_token())?
+        .text_range()
+        .start();
 
-            match ctx.config.snippet_cap {
-                Some(cap) => {
-                    let snippet = format!("{decl}$0;");
-                    item.snippet_edit(cap, TextEdit::replace(replacement_range, snippet));
-                }
-                None => {
-                    item.text_edit(TextEdit::replace(replacement_range, decl));
-                }
-            };
-            item.add_to(acc, ctx.db);
-        }
+    let len = end - start;
+    let mut decl = transformed_ty.syntax().text().slice(..len).to_string();
+    if !decl.ends_with(' ') {
+        decl.push(' ');
     }
+    decl.push_str("= ");
+    Some(decl)
+}
+
+/// Returns the type aliases out of `candidates` that the signature of `func` refers to as
+/// `Self::Name`.
+fn referenced_type_aliases(
+    ctx: &CompletionContext<'_>,
+    func: &ast::Fn,
+    candidates: &[hir::TypeAlias],
+) -> Vec<hir::TypeAlias> {
+    let signature = func
+        .param_list()
+        .map(|it| it.syntax().clone())
+        .into_iter()
+        .chain(func.ret_type().map(|it| it.syntax().clone()));
+    let referenced_names: Vec<_> = signature
+        .flat_map(|it| it.descendants())
+        .filter_map(ast::Path::cast)
+        .filter(|path| {
+            path.qualifier()
+                .and_then(|it| it.segment())
+                .map_or(false, |it| it.kind() == Some(ast::PathSegmentKind::SelfTypeKw))
+        })
+        .filter_map(|path| path.segment()?.name_ref())
+        .map(|name_ref| name_ref.text().to_string())
+        .collect();
+
+    candidates
+        .iter()
+        .filter(|type_alias| {
+            let name = type_alias.name(ctx.db).display(ctx.db).to_string();
+            referenced_names.contains(&name)
+        })
+        .copied()
+        .collect()
 }
 
 fn add_const_impl(
@@ -483,6 +553,7 @@ impl Test for T {
                 bn &self
                 bn mut self
                 bn self
+                bn self: Box<Self>
             "#]],
         );
 
@@ -742,6 +813,54 @@ impl Test for T {
         );
     }
 
+    #[test]
+    fn associated_type_after_type_keyword_offers_missing_only() {
+        check(
+            r#"
+trait Iterator {
+    type Item;
+    type Other;
+    fn next(&mut self);
+}
+
+impl Iterator for () {
+    type Other = ();
+    type $0
+}
+"#,
+            expect![[r#"
+                ta type Item =
+            "#]],
+        );
+        check_edit(
+            "type Item",
+            r#"
+trait Iterator {
+    type Item;
+    type Other;
+    fn next(&mut self);
+}
+
+impl Iterator for () {
+    type Other = ();
+    type $0
+}
+"#,
+            r#"
+trait Iterator {
+    type Item;
+    type Other;
+    fn next(&mut self);
+}
+
+impl Iterator for () {
+    type Other = ();
+    type Item = $0;
+}
+"#,
+        );
+    }
+
     #[test]
     fn associated_type() {
         check_edit(
@@ -1393,6 +1512,56 @@ trait Tr {
 impl Tr for () {
     type Item = $0;
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn scaffolds_associated_types_used_in_signature() {
+        check_edit(
+            "fn add",
+            r#"
+//- minicore: add
+struct Foo;
+
+impl core::ops::Add<Foo> for Foo {
+    fn a$0
+}
+"#,
+            r#"
+struct Foo;
+
+impl core::ops::Add<Foo> for Foo {
+    type Output = $1;
+
+    fn add(self, rhs: Foo) -> Self::Output {
+        $0
+    }
+}
+"#,
+        );
+        check_edit(
+            "fn add",
+            r#"
+//- minicore: add
+struct Foo;
+
+impl core::ops::Add<Foo> for Foo {
+    type Output = Foo;
+
+    fn a$0
+}
+"#,
+            r#"
+struct Foo;
+
+impl core::ops::Add<Foo> for Foo {
+    type Output = Foo;
+
+    fn add(self, rhs: Foo) -> Self::Output {
+    $0
+}
+}
 "#,
         );
     }
@@ -1,4 +1,4 @@
-//! Completes `where` and `for` keywords.
+//! Completes `where`, `for` and `as` keywords.
 
 use syntax::ast::{self, Item};
 
@@ -26,6 +26,17 @@ pub(crate) fn complete_for_and_where(
         | Item::Union(_) => {
             add_keyword("where", "where");
         }
+        Item::Use(it) => {
+            let renameable = it.use_tree().map_or(false, |tree| {
+                tree.path().is_some()
+                    && tree.rename().is_none()
+                    && tree.star_token().is_none()
+                    && tree.use_tree_list().is_none()
+            });
+            if renameable {
+                add_keyword("as", "as");
+            }
+        }
         _ => (),
     }
 }
@@ -41,6 +52,37 @@ mod tests {
         expect.assert_eq(&actual)
     }
 
+    #[test]
+    fn test_as_after_use_path() {
+        check(
+            r"
+mod foo { pub struct Bar; }
+use foo::Bar $0
+",
+            expect![[r#"
+                kw as
+            "#]],
+        );
+        check_edit(
+            "as",
+            r"
+mod foo { pub struct Bar; }
+use foo::Bar $0
+",
+            r"
+mod foo { pub struct Bar; }
+use foo::Bar as
+",
+        );
+        check(
+            r"
+mod foo { pub struct Bar; }
+use foo::Bar as Baz $0
+",
+            expect![[""]],
+        );
+    }
+
     #[test]
     fn test_else_edit_after_if() {
         check_edit(
@@ -72,7 +114,7 @@ mod tests {
 use core::future::*;
 struct A {}
 impl Future for A {}
-fn foo(a: A) { a.$0 }
+async fn foo(a: A) { a.$0 }
 "#,
             expect![[r#"
                 kw await                  expr.await
@@ -94,7 +136,7 @@ fn foo(a: A) { a.$0 }
             r#"
 //- minicore: future
 use std::future::*;
-fn foo() {
+async fn foo() {
     let a = async {};
     a.$0
 }
@@ -124,7 +166,34 @@ fn foo() {
 use core::future::*;
 struct A {}
 impl IntoFuture for A {}
-fn foo(a: A) { a.$0 }
+async fn foo(a: A) { a.$0 }
+"#,
+            expect![[r#"
+                kw await                  expr.await
+                me into_future() (as IntoFuture) fn(self) -> <Self as IntoFuture>::IntoFuture
+                sn box                    Box::new(expr)
+                sn call                   function(expr)
+                sn dbg                    dbg!(expr)
+                sn dbgr                   dbg!(&expr)
+                sn let                    let
+                sn letm                   let mut
+                sn match                  match expr {}
+                sn ref                    &expr
+                sn refm                   &mut expr
+                sn unsafe                 unsafe {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_completion_await_in_async_block_and_closure() {
+        check(
+            r#"
+//- minicore: future
+use core::future::*;
+struct A {}
+impl Future for A {}
+fn foo(a: A) { async { a.$0 }; }
 "#,
             expect![[r#"
                 kw await                  expr.await
@@ -143,6 +212,54 @@ fn foo(a: A) { a.$0 }
         );
     }
 
+    #[test]
+    fn test_no_await_outside_of_async_context() {
+        check(
+            r#"
+//- minicore: future
+use core::future::*;
+struct A {}
+impl Future for A {}
+fn foo(a: A) { a.$0 }
+"#,
+            expect![[r#"
+                me into_future() (as IntoFuture) fn(self) -> <Self as IntoFuture>::IntoFuture
+                sn box                    Box::new(expr)
+                sn call                   function(expr)
+                sn dbg                    dbg!(expr)
+                sn dbgr                   dbg!(&expr)
+                sn let                    let
+                sn letm                   let mut
+                sn match                  match expr {}
+                sn ref                    &expr
+                sn refm                   &mut expr
+                sn unsafe                 unsafe {}
+            "#]],
+        );
+        check(
+            r#"
+//- minicore: future
+use core::future::*;
+struct A {}
+impl Future for A {}
+async fn foo(a: A) { let _ = || a.$0; }
+"#,
+            expect![[r#"
+                me into_future() (as IntoFuture) fn(self) -> <Self as IntoFuture>::IntoFuture
+                sn box                    Box::new(expr)
+                sn call                   function(expr)
+                sn dbg                    dbg!(expr)
+                sn dbgr                   dbg!(&expr)
+                sn let                    let
+                sn letm                   let mut
+                sn match                  match expr {}
+                sn ref                    &expr
+                sn refm                   &mut expr
+                sn unsafe                 unsafe {}
+            "#]],
+        );
+    }
+
     #[test]
     fn let_semi() {
         cov_mark::check!(let_semi);
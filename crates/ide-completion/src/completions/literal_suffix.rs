@@ -0,0 +1,121 @@
+//! Completes type suffixes of integer literals, like `42u$0`.
+
+use syntax::{
+    ast::{self, Radix},
+    AstToken, TextRange, TextSize,
+};
+
+use crate::{context::CompletionContext, CompletionItem, CompletionItemKind, Completions};
+
+const INT_SUFFIXES: &[&str] =
+    &["i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize"];
+const FLOAT_SUFFIXES: &[&str] = &["f32", "f64"];
+
+/// Complete the type suffix at the end of an integer literal.
+pub(crate) fn complete_int_suffix(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    number: &ast::IntNumber,
+) -> Option<()> {
+    let range = number.syntax().text_range();
+    if range.end() != ctx.position.offset {
+        return None;
+    }
+    let (_, digits, suffix) = number.split_into_parts();
+    if digits.is_empty() {
+        return None;
+    }
+
+    // Float suffixes are only valid on decimal literals.
+    let float_suffixes = match number.radix() {
+        Radix::Decimal => FLOAT_SUFFIXES,
+        _ => &[],
+    };
+    let source_range = TextRange::new(range.end() - TextSize::of(suffix), range.end());
+    for &ty in INT_SUFFIXES.iter().chain(float_suffixes) {
+        CompletionItem::new(CompletionItemKind::BuiltinType, source_range, ty).add_to(acc, ctx.db);
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::tests::{check_edit, completion_list};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn completes_suffixes() {
+        check(
+            r#"
+fn main() {
+    let _ = 42$0;
+}
+"#,
+            expect![[r#"
+                bt f32
+                bt f64
+                bt i128
+                bt i16
+                bt i32
+                bt i64
+                bt i8
+                bt isize
+                bt u128
+                bt u16
+                bt u32
+                bt u64
+                bt u8
+                bt usize
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_float_suffixes_for_hex_literals() {
+        check(
+            r#"
+fn main() {
+    let _ = 0x42$0;
+}
+"#,
+            expect![[r#"
+                bt i128
+                bt i16
+                bt i32
+                bt i64
+                bt i8
+                bt isize
+                bt u128
+                bt u16
+                bt u32
+                bt u64
+                bt u8
+                bt usize
+            "#]],
+        );
+    }
+
+    #[test]
+    fn replaces_partial_suffix() {
+        check_edit(
+            "u64",
+            r#"
+fn main() {
+    let _ = 42u6$0;
+}
+"#,
+            r#"
+fn main() {
+    let _ = 42u64;
+}
+"#,
+        );
+    }
+}
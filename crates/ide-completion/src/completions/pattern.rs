@@ -39,6 +39,13 @@ pub(crate) fn complete_pattern(
         }
     }
 
+    if let Some(Pat::SlicePat(slice)) = &pattern_ctx.parent_pat {
+        // only a single rest pattern is allowed per slice pattern
+        if !slice.pats().any(|it| matches!(it, Pat::RestPat(_))) {
+            acc.add_keyword(ctx, "..");
+        }
+    }
+
     if pattern_ctx.record_pat.is_some() {
         return;
     }
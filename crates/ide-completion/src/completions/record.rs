@@ -43,7 +43,9 @@ pub(crate) fn complete_record_expr_fields(
 ) {
     let ty = ctx.sema.type_of_expr(&Expr::RecordExpr(record_expr.clone()));
 
-    let missing_fields = match ty.as_ref().and_then(|t| t.original.as_adt()) {
+    let adt = ty.as_ref().and_then(|t| t.original.as_adt());
+    let is_union = matches!(adt, Some(hir::Adt::Union(_)));
+    let mut missing_fields = match adt {
         Some(hir::Adt::Union(un)) => {
             // ctx.sema.record_literal_missing_fields will always return
             // an empty Vec on a union literal. This is normally
@@ -62,7 +64,8 @@ pub(crate) fn complete_record_expr_fields(
 
             if !missing_fields.is_empty() {
                 cov_mark::hit!(functional_update_field);
-                add_default_update(acc, ctx, ty);
+                add_default_update(acc, ctx, ty.clone());
+                add_local_update(acc, ctx, ty);
             }
             if dot_prefix {
                 cov_mark::hit!(functional_update_one_dot);
@@ -75,6 +78,17 @@ pub(crate) fn complete_record_expr_fields(
             missing_fields
         }
     };
+    // Fields with a local of the same name and a fitting type in scope can make use of the field
+    // init shorthand, unions can only ever be initialized with a single field so don't bother.
+    if !is_union {
+        missing_fields.retain(|(field, ty)| match ctx.locals.get(&field.name(ctx.db)) {
+            Some(&local) if local.ty(ctx.db).could_coerce_to(ctx.db, ty) => {
+                acc.add_field_shorthand(ctx, *field, ty, local);
+                false
+            }
+            _ => true,
+        });
+    }
     complete_fields(acc, ctx, missing_fields);
 }
 
@@ -102,6 +116,26 @@ pub(crate) fn add_default_update(
     }
 }
 
+/// Offers `..local` for every local in scope whose type matches the record being constructed.
+fn add_local_update(acc: &mut Completions, ctx: &CompletionContext<'_>, ty: Option<hir::TypeInfo>) {
+    let Some(ty) = ty else { return };
+    for (name, local) in ctx.locals.iter() {
+        if local.ty(ctx.db) != ty.original {
+            continue;
+        }
+        let completion_text = format!("..{}", name.display(ctx.db));
+        let mut item =
+            CompletionItem::new(SymbolKind::Field, ctx.source_range(), completion_text.clone());
+        let insert_text =
+            completion_text.strip_prefix(ctx.token.text()).unwrap_or(&completion_text);
+        item.insert_text(insert_text).set_relevance(CompletionRelevance {
+            postfix_match: Some(CompletionRelevancePostfixMatch::Exact),
+            ..Default::default()
+        });
+        item.add_to(acc, ctx.db);
+    }
+}
+
 fn complete_fields(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -118,6 +152,7 @@ fn complete_fields(
             None,
             field,
             &ty,
+            false,
         );
     }
 }
@@ -452,6 +487,27 @@ struct S {
 fn main() {
     S {field
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn local_update_completion_edit() {
+        check_edit(
+            "..local",
+            r#"
+struct Foo { x: u32, y: u32 }
+
+fn foo(local: Foo) {
+    let other = Foo { x: 1, $0 };
+}
+"#,
+            r#"
+struct Foo { x: u32, y: u32 }
+
+fn foo(local: Foo) {
+    let other = Foo { x: 1, ..local };
+}
 "#,
         );
     }
@@ -12,7 +12,7 @@ pub(crate) fn complete_expr_snippet(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
     path_ctx: &PathCompletionCtx,
-    &ExprCtx { in_block_expr, .. }: &ExprCtx,
+    &ExprCtx { in_block_expr, ref is_func_update, .. }: &ExprCtx,
 ) {
     if !matches!(path_ctx.qualified, Qualified::No) {
         return;
@@ -30,6 +30,11 @@ pub(crate) fn complete_expr_snippet(
         add_custom_completions(acc, ctx, cap, SnippetScope::Expr);
     }
 
+    // In blocks `const` is already offered as a keyword, which also covers const items.
+    if is_func_update.is_none() && !in_block_expr {
+        acc.add_const_block(ctx, cap);
+    }
+
     if in_block_expr {
         snippet(ctx, cap, "pd", "eprintln!(\"$0 = {:?}\", $0);").add_to(acc, ctx.db);
         snippet(ctx, cap, "ppd", "eprintln!(\"$0 = {:#?}\", $0);").add_to(acc, ctx.db);
@@ -115,6 +120,12 @@ macro_rules! $1 {
         );
         item.add_to(acc, ctx.db);
     }
+
+    if let ItemListKind::Impl = kind {
+        snippet(ctx, cap, "fn (Method)", "fn $1(&self) {\n    $0\n}").add_to(acc, ctx.db);
+        snippet(ctx, cap, "const (Associated constant)", "const $1: $2 = $0;").add_to(acc, ctx.db);
+        snippet(ctx, cap, "type (Associated type)", "type $1 = $0;").add_to(acc, ctx.db);
+    }
 }
 
 fn snippet(ctx: &CompletionContext<'_>, cap: SnippetCap, label: &str, snippet: &str) -> Builder {
@@ -154,10 +165,34 @@ fn add_custom_completions(
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests::{check_edit_with_config, TEST_CONFIG},
+        tests::{check_edit, check_edit_with_config, completion_list, TEST_CONFIG},
         CompletionConfig, Snippet,
     };
 
+    #[test]
+    fn const_block_in_expr_position() {
+        check_edit(
+            "const",
+            r#"
+fn main() { let x = $0; }
+"#,
+            r#"
+fn main() { let x = const { $0 }; }
+"#,
+        );
+    }
+
+    #[test]
+    fn no_const_block_next_to_const_keyword() {
+        let actual = completion_list(
+            r#"
+fn main() { $0 }
+"#,
+        );
+        assert!(actual.contains("kw const\n"), "{actual}");
+        assert!(!actual.contains("sn const\n"), "{actual}");
+    }
+
     #[test]
     fn custom_snippet_completion() {
         check_edit_with_config(
@@ -1,12 +1,14 @@
 //! Completion of names from the current scope in type position.
 
 use hir::{HirDisplay, ScopeDef};
-use syntax::{ast, AstNode};
+use ide_db::SymbolKind;
+use syntax::{ast, match_ast, AstNode};
 
 use crate::{
     context::{PathCompletionCtx, Qualified, TypeAscriptionTarget, TypeLocation},
-    render::render_type_inference,
-    CompletionContext, Completions,
+    item::CompletionRelevanceTypeMatch,
+    render::{render_type_inference, res_to_kind},
+    CompletionContext, CompletionItem, CompletionRelevance, Completions,
 };
 
 pub(crate) fn complete_type_path(
@@ -140,6 +142,9 @@ pub(crate) fn complete_type_path(
             match location {
                 TypeLocation::TypeBound => {
                     acc.add_nameref_keywords_with_colon(ctx);
+                    if allows_relaxed_bound(ctx) {
+                        acc.add_keyword(ctx, "?Sized");
+                    }
                     ctx.process_all_names(&mut |name, res, doc_aliases| {
                         let add_resolution = match res {
                             ScopeDef::ModuleDef(hir::ModuleDef::Macro(mac)) => {
@@ -184,19 +189,167 @@ pub(crate) fn complete_type_path(
                         }
                     }
                 }
+                TypeLocation::Other if is_trait_method_where_pred(&path_ctx.path) => {
+                    acc.add_keyword_snippet(ctx, "Self: Sized", "Self: Sized");
+                    acc.add_keyword_snippet(ctx, "Self: 'static", "Self: 'static");
+                }
+                TypeLocation::TypeAscription(TypeAscriptionTarget::RetType(_)) => {
+                    let in_fn_ret_type = ctx
+                        .token
+                        .parent_ancestors()
+                        .find_map(ast::RetType::cast)
+                        .and_then(|ret_type| ret_type.syntax().parent())
+                        .map_or(false, |parent| ast::Fn::can_cast(parent.kind()));
+                    if in_fn_ret_type {
+                        acc.add_keyword_snippet(ctx, "impl", "impl $0");
+                        acc.add_keyword_snippet(ctx, "dyn", "dyn $0");
+                    }
+                }
                 _ => {}
             };
 
+            let collect_target = match location {
+                TypeLocation::GenericArg { args: Some(arg_list), .. } => {
+                    collect_target_requirements(ctx, arg_list)
+                }
+                _ => None,
+            };
+
+            let const_arg_ty = match location {
+                TypeLocation::GenericArg {
+                    corresponding_param: Some(ast::GenericParam::ConstParam(param)),
+                    ..
+                } => ctx.sema.to_def(param).map(|it| it.ty(ctx.db)),
+                _ => None,
+            };
+
             acc.add_nameref_keywords_with_colon(ctx);
+            if let (
+                TypeLocation::GenericArg {
+                    corresponding_param: Some(ast::GenericParam::ConstParam(_)),
+                    ..
+                },
+                Some(cap),
+            ) = (location, ctx.config.snippet_cap)
+            {
+                // const expressions other than literals and paths need to be wrapped in braces
+                acc.add_const_arg_block(ctx, cap);
+            }
             ctx.process_all_names(&mut |name, def, doc_aliases| {
-                if scope_def_applicable(def) {
-                    acc.add_path_resolution(ctx, path_ctx, name, def, doc_aliases);
+                if let Some(const_arg_ty) = &const_arg_ty {
+                    let ty = match def {
+                        ScopeDef::ModuleDef(hir::ModuleDef::Adt(adt)) => Some(adt.ty(ctx.db)),
+                        ScopeDef::ModuleDef(hir::ModuleDef::BuiltinType(it)) => Some(it.ty(ctx.db)),
+                        _ => None,
+                    };
+                    if let Some(ty) = ty {
+                        add_assoc_consts_for_const_arg(acc, ctx, const_arg_ty, &ty, &name);
+                    }
+                }
+                if !scope_def_applicable(def) {
+                    return;
+                }
+                if let (
+                    Some((from_iterator, item_ty)),
+                    ScopeDef::ModuleDef(hir::ModuleDef::Adt(adt)),
+                ) = (&collect_target, def)
+                {
+                    if adt.ty(ctx.db).impls_trait(ctx.db, *from_iterator, &[item_ty.clone()]) {
+                        add_collect_target(acc, ctx, adt, &name);
+                        return;
+                    }
                 }
+                acc.add_path_resolution(ctx, path_ctx, name, def, doc_aliases);
             });
         }
     }
 }
 
+/// For the turbofish of `iter.collect::<$0>()`, returns the `FromIterator` trait and the item type
+/// of the iterator, which the collection types offered here have to be able to collect.
+fn collect_target_requirements(
+    ctx: &CompletionContext<'_>,
+    arg_list: &ast::GenericArgList,
+) -> Option<(hir::Trait, hir::Type)> {
+    let call = ast::MethodCallExpr::cast(arg_list.syntax().parent()?)?;
+    if call.name_ref()?.text() != "collect" {
+        return None;
+    }
+    let famous_defs = ctx.famous_defs();
+    let iterator = famous_defs.core_iter_Iterator()?;
+    let from_iterator = famous_defs.core_iter_FromIterator()?;
+    let item = iterator.items(ctx.db).into_iter().find_map(|item| match item {
+        hir::AssocItem::TypeAlias(alias) if alias.name(ctx.db) == hir::known::Item => Some(alias),
+        _ => None,
+    })?;
+    let receiver_ty = ctx.sema.type_of_expr(&call.receiver()?)?.original;
+    let item_ty = receiver_ty.normalize_trait_assoc_type(ctx.db, &[], item)?;
+    Some((from_iterator, item_ty))
+}
+
+/// Adds a collection type for `collect::<$0>()`, with its required type parameters left to
+/// inference like in `Vec<_>`.
+fn add_collect_target(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    adt: hir::Adt,
+    name: &hir::Name,
+) {
+    let n_inferred_params = hir::GenericDef::from(adt)
+        .params(ctx.db)
+        .into_iter()
+        .filter(|param| match param {
+            hir::GenericParam::TypeParam(it) => it.default(ctx.db).is_none(),
+            _ => false,
+        })
+        .count();
+    let mut label = name.to_smol_str().to_string();
+    if n_inferred_params > 0 {
+        label.push('<');
+        label.push_str(&vec!["_"; n_inferred_params].join(", "));
+        label.push('>');
+    }
+    let mut item = CompletionItem::new(
+        res_to_kind(ScopeDef::ModuleDef(adt.into())),
+        ctx.source_range(),
+        label,
+    );
+    item.set_relevance(CompletionRelevance {
+        type_match: Some(CompletionRelevanceTypeMatch::Exact),
+        ..CompletionRelevance::default()
+    });
+    item.add_to(acc, ctx.db);
+}
+
+/// Adds the associated consts of `ty` that fit a const generic argument of type `const_arg_ty`,
+/// wrapped in the braces a path needs to be used as a const argument, like `{ Bar::N }`.
+fn add_assoc_consts_for_const_arg(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    const_arg_ty: &hir::Type,
+    ty: &hir::Type,
+    name: &hir::Name,
+) {
+    ty.iterate_assoc_items(ctx.db, ctx.krate, |item| {
+        let hir::AssocItem::Const(ct) = item else { return None::<()> };
+        let ct_name = ct.name(ctx.db)?;
+        let ty = ct.ty(ctx.db);
+        if !ty.could_unify_with(ctx.db, const_arg_ty) {
+            return None;
+        }
+        let path = format!("{}::{}", name.display(ctx.db), ct_name.display(ctx.db));
+        let mut item = CompletionItem::new(SymbolKind::Const, ctx.source_range(), path.clone());
+        item.insert_text(format!("{{ {path} }}"))
+            .detail(ty.display(ctx.db).to_string())
+            .set_relevance(CompletionRelevance {
+                type_match: Some(CompletionRelevanceTypeMatch::Exact),
+                ..CompletionRelevance::default()
+            });
+        item.add_to(acc, ctx.db);
+        None
+    });
+}
+
 pub(crate) fn complete_ascribed_type(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -219,3 +372,55 @@ pub(crate) fn complete_ascribed_type(
     acc.add(render_type_inference(ty_string, ctx));
     None
 }
+
+/// Whether `path` is the bounded type of a where predicate of a trait method.
+fn is_trait_method_where_pred(path: &ast::Path) -> bool {
+    let Some(pred) = path.syntax().ancestors().find_map(ast::WherePred::cast) else {
+        return false;
+    };
+    if pred
+        .ty()
+        .map_or(true, |ty| !ty.syntax().text_range().contains_range(path.syntax().text_range()))
+    {
+        return false;
+    }
+    pred.syntax()
+        .parent()
+        .and_then(|where_clause| where_clause.parent())
+        .filter(|it| ast::Fn::can_cast(it.kind()))
+        .and_then(|fn_| fn_.parent())
+        .and_then(|item_list| item_list.parent())
+        .map_or(false, |it| ast::Trait::can_cast(it.kind()))
+}
+
+/// Whether the bound being completed may be the relaxed `?Sized` bound, that is whether it bounds a
+/// type parameter and isn't relaxed already.
+fn allows_relaxed_bound(ctx: &CompletionContext<'_>) -> bool {
+    let mut ancestors = ctx.token.parent_ancestors();
+    if ancestors
+        .clone()
+        .find_map(ast::TypeBound::cast)
+        .map_or(false, |bound| bound.question_mark_token().is_some())
+    {
+        return false;
+    }
+    let Some(bound_list) = ancestors.find_map(ast::TypeBoundList::cast) else {
+        return false;
+    };
+    match bound_list.syntax().parent() {
+        Some(parent) => match_ast! {
+            match parent {
+                ast::TypeParam(_) => true,
+                ast::WherePred(it) => it.ty().is_some(),
+                // Only associated types of traits can have bounds, `type A: ?Sized;`
+                ast::TypeAlias(it) => it
+                    .syntax()
+                    .ancestors()
+                    .nth(2)
+                    .map_or(false, |it| ast::Trait::can_cast(it.kind())),
+                _ => false,
+            }
+        },
+        None => false,
+    }
+}
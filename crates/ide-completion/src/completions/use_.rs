@@ -30,13 +30,20 @@ pub(crate) fn complete_use_path(
                 acc.add_keyword(ctx, "self");
             }
 
+            // Names that are already members of the use tree group we are completing in. Plain
+            // members are skipped, renamed ones are still offered but ranked lower.
+            let mut group_members = FxHashSet::default();
             let mut already_imported_names = FxHashSet::default();
             if let Some(list) = ctx.token.parent_ancestors().find_map(ast::UseTreeList::cast) {
                 let use_tree = list.parent_use_tree();
                 if use_tree.path().as_ref() == Some(path) {
                     for tree in list.use_trees().filter(|tree| tree.is_simple_path()) {
                         if let Some(name) = tree.path().and_then(|path| path.as_single_name_ref()) {
-                            already_imported_names.insert(name.to_string());
+                            if tree.rename().is_some() {
+                                already_imported_names.insert(name.to_string());
+                            } else {
+                                group_members.insert(name.to_string());
+                            }
                         }
                     }
                 }
@@ -55,6 +62,13 @@ pub(crate) fn complete_use_path(
                         if !ctx.check_stability(def.attrs(ctx.db).as_deref()) {
                             continue;
                         }
+                        if name
+                            .as_text()
+                            .map_or(false, |text| group_members.contains(text.as_str()))
+                        {
+                            cov_mark::hit!(skip_use_tree_group_members);
+                            continue;
+                        }
                         let is_name_already_imported = name
                             .as_text()
                             .map_or(false, |text| already_imported_names.contains(text.as_str()));
@@ -4,7 +4,7 @@ mod analysis;
 #[cfg(test)]
 mod tests;
 
-use std::iter;
+use std::{cell::RefCell, iter};
 
 use hir::{
     HasAttrs, Local, Name, PathResolution, ScopeDef, Semantics, SemanticsScope, Type, TypeInfo,
@@ -17,14 +17,14 @@ use ide_db::{
 };
 use syntax::{
     ast::{self, AttrKind, NameOrNameRef},
-    AstNode, SmolStr,
+    match_ast, AstNode, SmolStr,
     SyntaxKind::{self, *},
     SyntaxToken, TextRange, TextSize, T,
 };
 use text_edit::Indel;
 
 use crate::{
-    context::analysis::{expand_and_analyze, AnalysisResult},
+    context::analysis::{expand_and_analyze, expected_trait_bounds, AnalysisResult},
     CompletionConfig,
 };
 
@@ -136,6 +136,9 @@ pub(crate) struct ExprCtx {
     pub(crate) in_block_expr: bool,
     pub(crate) in_loop_body: bool,
     pub(crate) after_if_expr: bool,
+    /// Whether this expression directly follows a `let` statement with a refutable pattern that
+    /// is missing both its `else` block and its semicolon, like `let Some(x) = opt $0`.
+    pub(crate) after_refutable_let: bool,
     /// Whether this expression is the direct condition of an if or while expression
     pub(crate) in_condition: bool,
     pub(crate) incomplete_let: bool,
@@ -367,6 +370,12 @@ pub(super) enum CompletionAnalysis {
         /// fake token
         expanded: Option<ast::String>,
     },
+    /// The doc comment the cursor is currently inside
+    DocComment(ast::Comment),
+    /// The integer literal the cursor is currently inside
+    IntNumber(ast::IntNumber),
+    /// The char literal the cursor is currently inside
+    Char(ast::Char),
     /// Set if we are currently completing in an unexpanded attribute, this usually implies a builtin attribute like `allow($0)`
     UnexpandedAttrTT {
         colon_prefix: bool,
@@ -427,6 +436,9 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) expected_name: Option<NameOrNameRef>,
     /// The expected type of what we are completing.
     pub(super) expected_type: Option<Type>,
+    /// The traits the expected type is required to implement.
+    /// These are the trait bounds of the generic parameter of the function argument we are completing.
+    pub(super) expected_trait_bounds: Vec<hir::Trait>,
 
     pub(super) qualifier_ctx: QualifierCtx,
 
@@ -438,6 +450,10 @@ pub(crate) struct CompletionContext<'a> {
     ///   - mod bar
     /// Here depth will be 2
     pub(super) depth_from_crate_root: usize,
+
+    /// Caches whether a module or one of its ancestors is `#[doc(hidden)]`, as this is looked up
+    /// for every rendered item.
+    doc_hidden_modules: RefCell<FxHashMap<hir::Module, bool>>,
 }
 
 impl CompletionContext<'_> {
@@ -456,6 +472,53 @@ impl CompletionContext<'_> {
         }
     }
 
+    /// Whether the completion position is inside of an `async` function, closure or block, that is
+    /// whether `.await` may be used here.
+    pub(crate) fn is_in_async_context(&self) -> bool {
+        self.token
+            .parent_ancestors()
+            .find_map(|node| {
+                match_ast! {
+                    match node {
+                        ast::BlockExpr(it) => it.async_token().map(|_| true),
+                        ast::ClosureExpr(it) => Some(it.async_token().is_some()),
+                        ast::Fn(it) => Some(it.async_token().is_some()),
+                        ast::Item(_) => Some(false),
+                        _ => None,
+                    }
+                }
+            })
+            .unwrap_or(false)
+    }
+
+    /// The part of the identifier under the cursor that has been typed so far, if any.
+    pub(crate) fn typed_name(&self) -> Option<&str> {
+        if self.original_token.kind() != IDENT {
+            return None;
+        }
+        let typed_len = self.position.offset - self.original_token.text_range().start();
+        self.original_token.text().get(..usize::from(typed_len)).filter(|it| !it.is_empty())
+    }
+
+    /// Whether an item called `name` can pass the client's fuzzy filtering of the identifier typed
+    /// so far, that is whether the typed text is a case-insensitive subsequence of the name or one
+    /// of its doc aliases.
+    ///
+    /// This always holds unless [`CompletionConfig::enable_prefilter`] is set.
+    pub(crate) fn could_match_typed_name(&self, name: &str, doc_aliases: &[SmolStr]) -> bool {
+        if !self.config.enable_prefilter {
+            return true;
+        }
+        let Some(typed) = self.typed_name() else { return true };
+        let could_match = iter::once(name)
+            .chain(doc_aliases.iter().map(SmolStr::as_str))
+            .any(|candidate| fuzzy_match_score(typed, candidate).is_some());
+        if !could_match {
+            cov_mark::hit!(prefilter_skips_non_matching_item);
+        }
+        could_match
+    }
+
     pub(crate) fn famous_defs(&self) -> FamousDefs<'_, '_> {
         FamousDefs(&self.sema, self.krate)
     }
@@ -503,6 +566,11 @@ impl CompletionContext<'_> {
         attrs.doc_aliases().collect()
     }
 
+    /// Checks whether introducing a binding called `name` would shadow a local that is in scope.
+    pub(crate) fn is_shadowing_local(&self, name: &Name) -> bool {
+        self.locals.contains_key(name)
+    }
+
     /// Check if an item is `#[doc(hidden)]`.
     pub(crate) fn is_item_hidden(&self, item: &hir::ItemInNs) -> bool {
         let attrs = item.attrs(self.db);
@@ -513,6 +581,21 @@ impl CompletionContext<'_> {
         }
     }
 
+    /// Whether import completions may offer `item` given the crate defining it. Unless
+    /// [`CompletionConfig::imports_from_direct_deps_only`] is set, this holds for all crates.
+    pub(crate) fn is_from_importable_crate(&self, item: &hir::ItemInNs) -> bool {
+        if !self.config.imports_from_direct_deps_only {
+            return true;
+        }
+        match item.krate(self.db) {
+            Some(krate) => {
+                krate == self.krate
+                    || self.krate.dependencies(self.db).iter().any(|dep| dep.krate == krate)
+            }
+            None => true,
+        }
+    }
+
     /// Checks whether this item should be listed in regards to stability. Returns `true` if we should.
     pub(crate) fn check_stability(&self, attrs: Option<&hir::Attrs>) -> bool {
         let Some(attrs) = attrs else {
@@ -612,6 +695,17 @@ impl CompletionContext<'_> {
         }
     }
 
+    /// Checks whether `module` or any of its ancestors is `#[doc(hidden)]`.
+    pub(crate) fn is_module_doc_hidden(&self, module: hir::Module) -> bool {
+        if let Some(&hidden) = self.doc_hidden_modules.borrow().get(&module) {
+            return hidden;
+        }
+        let hidden = module.attrs(self.db).has_doc_hidden()
+            || module.parent(self.db).map_or(false, |parent| self.is_module_doc_hidden(parent));
+        self.doc_hidden_modules.borrow_mut().insert(module, hidden);
+        hidden
+    }
+
     fn is_doc_hidden(&self, attrs: &hir::Attrs, defining_crate: hir::Crate) -> bool {
         // `doc(hidden)` items are only completed within the defining crate.
         self.krate != defining_crate && attrs.has_doc_hidden()
@@ -706,6 +800,11 @@ impl<'a> CompletionContext<'a> {
             }
         });
 
+        let expected_trait_bounds = match expected_type {
+            Some(_) => expected_trait_bounds(&sema, &token, krate),
+            None => Vec::new(),
+        };
+
         let depth_from_crate_root = iter::successors(module.parent(db), |m| m.parent(db)).count();
 
         let ctx = CompletionContext {
@@ -721,14 +820,51 @@ impl<'a> CompletionContext<'a> {
             is_nightly,
             expected_name,
             expected_type,
+            expected_trait_bounds,
             qualifier_ctx,
             locals,
             depth_from_crate_root,
+            doc_hidden_modules: Default::default(),
         };
         Some((ctx, analysis))
     }
 }
 
+/// Scores how well `typed` fuzzy matches `candidate`, returning `None` if `typed` is not a
+/// case-insensitive subsequence of `candidate`.
+///
+/// Every matched character counts, with bonuses for matching the start of `candidate`, for
+/// continuing a contiguous run of matched characters and for matching at a word boundary.
+pub(crate) fn fuzzy_match_score(typed: &str, candidate: &str) -> Option<u32> {
+    let mut candidate = candidate.chars().enumerate();
+    let mut prev_char = None;
+    let mut last_match = None;
+    let mut score = 0;
+    for typed_char in typed.chars() {
+        let (idx, is_word_start) = loop {
+            let (idx, c) = candidate.next()?;
+            let prev = prev_char.replace(c);
+            if c.to_lowercase().eq(typed_char.to_lowercase()) {
+                let is_word_start = prev.map_or(true, |prev: char| {
+                    prev == '_' || (prev.is_lowercase() && c.is_uppercase())
+                });
+                break (idx, is_word_start);
+            }
+        };
+        score += 1;
+        if idx == 0 {
+            score += 3;
+        } else if is_word_start {
+            score += 1;
+        }
+        if last_match.is_some_and(|last| last + 1 == idx) {
+            score += 2;
+        }
+        last_match = Some(idx);
+    }
+    Some(score)
+}
+
 const OP_TRAIT_LANG_NAMES: &[&str] = &[
     "add_assign",
     "add",
@@ -2,7 +2,11 @@
 use std::iter;
 
 use hir::{Semantics, Type, TypeInfo, Variant};
-use ide_db::{active_parameter::ActiveParameter, RootDatabase};
+use ide_db::{
+    active_parameter::{callable_for_token, ActiveParameter},
+    famous_defs::FamousDefs,
+    RootDatabase,
+};
 use syntax::{
     algo::{find_node_at_offset, non_trivia_sibling},
     ast::{self, AttrKind, HasArgList, HasGenericParams, HasLoopBody, HasName, NameOrNameRef},
@@ -244,6 +248,14 @@ fn analyze(
     let Some(name_like) = find_node_at_offset(&speculative_file, offset) else {
         let analysis = if let Some(original) = ast::String::cast(original_token.clone()) {
             CompletionAnalysis::String { original, expanded: ast::String::cast(self_token.clone()) }
+        } else if let Some(comment) =
+            ast::Comment::cast(original_token.clone()).filter(ast::Comment::is_doc)
+        {
+            CompletionAnalysis::DocComment(comment)
+        } else if let Some(number) = ast::IntNumber::cast(original_token.clone()) {
+            CompletionAnalysis::IntNumber(number)
+        } else if let Some(literal) = ast::Char::cast(original_token.clone()) {
+            CompletionAnalysis::Char(literal)
         } else {
             // Fix up trailing whitespace problem
             // #[attr(foo = $0
@@ -306,6 +318,36 @@ fn analyze(
     Some((analysis, expected, qual_ctx))
 }
 
+/// Calculate the trait bounds of the generic parameter the function argument at the cursor position
+/// is passed for, ignoring `Sized` and generic traits.
+pub(super) fn expected_trait_bounds(
+    sema: &Semantics<'_, RootDatabase>,
+    token: &SyntaxToken,
+    krate: hir::Crate,
+) -> Vec<hir::Trait> {
+    let Some((callable, Some(idx))) = callable_for_token(sema, token.clone()) else {
+        return Vec::new();
+    };
+    let hir::CallableKind::Function(func) = callable.kind() else {
+        return Vec::new();
+    };
+    let params = match callable.receiver_param(sema.db) {
+        Some(_) => func.params_without_self(sema.db),
+        None => func.assoc_fn_params(sema.db),
+    };
+    let Some(param) = params.get(idx).and_then(|param| param.ty().as_type_param(sema.db)) else {
+        return Vec::new();
+    };
+    let sized = FamousDefs(sema, krate).core_marker_Sized();
+    param
+        .trait_bounds(sema.db)
+        .into_iter()
+        .filter(|&trait_| {
+            Some(trait_) != sized && trait_.type_or_const_param_count(sema.db, false) == 0
+        })
+        .collect()
+}
+
 /// Calculate the expected type and name of the cursor position.
 fn expected_type_and_name(
     sema: &Semantics<'_, RootDatabase>,
@@ -687,6 +729,25 @@ fn classify_name_ref(
         })();
         matches!(prev_expr, Some(ast::Expr::IfExpr(_)))
     };
+    let after_refutable_let = |node: SyntaxNode| {
+        (|| {
+            let node = match node.parent().and_then(ast::ExprStmt::cast) {
+                Some(stmt) => stmt.syntax().clone(),
+                None => node,
+            };
+            let prev_sibling = non_trivia_sibling(node.into(), Direction::Prev)?.into_node()?;
+            let let_stmt = ast::LetStmt::cast(prev_sibling)?;
+            if let_stmt.semicolon_token().is_some()
+                || let_stmt.let_else().is_some()
+                || let_stmt.initializer().is_none()
+            {
+                return None;
+            }
+            let pat = find_opt_node_in_file(original_file, let_stmt.pat())?;
+            Some(is_refutable_pat(sema, &pat))
+        })()
+        .unwrap_or(false)
+    };
 
     // We do not want to generate path completions when we are sandwiched between an item decl signature and its body.
     // ex. trait Foo $0 {}
@@ -716,6 +777,7 @@ fn classify_name_ref(
                     ast::Item::Trait(it) => it.assoc_item_list().is_none(),
                     ast::Item::TypeAlias(it) => it.ty().is_none() && it.semicolon_token().is_none(),
                     ast::Item::Union(it) => it.record_field_list().is_none(),
+                    ast::Item::Use(it) => it.semicolon_token().is_none(),
                     _ => false,
                 };
                 if is_inbetween {
@@ -943,6 +1005,7 @@ fn classify_name_ref(
         let in_block_expr = is_in_block(it);
         let in_loop_body = is_in_loop_body(it);
         let after_if_expr = after_if_expr(it.clone());
+        let after_refutable_let = after_refutable_let(it.clone());
         let ref_expr_parent =
             path.as_single_name_ref().and_then(|_| it.parent()).and_then(ast::RefExpr::cast);
         let (innermost_ret_ty, self_param) = {
@@ -1011,6 +1074,7 @@ fn classify_name_ref(
                 in_block_expr,
                 in_loop_body,
                 after_if_expr,
+                after_refutable_let,
                 in_condition,
                 ref_expr_parent,
                 is_func_update,
@@ -1392,6 +1456,32 @@ fn fetch_immediate_impl(
     }
 }
 
+/// Whether `pat` might not match, based on the literals and enum variants it contains.
+fn is_refutable_pat(sema: &Semantics<'_, RootDatabase>, pat: &ast::Pat) -> bool {
+    let is_refutable_def = |def| match def {
+        hir::ModuleDef::Variant(variant) => {
+            variant.parent_enum(sema.db).variants(sema.db).len() > 1
+        }
+        hir::ModuleDef::Const(_) | hir::ModuleDef::Static(_) => true,
+        _ => false,
+    };
+    let is_refutable_path =
+        |path: Option<ast::Path>| match path.and_then(|it| sema.resolve_path(&it)) {
+            Some(hir::PathResolution::Def(def)) => is_refutable_def(def),
+            _ => false,
+        };
+    pat.syntax().descendants().filter_map(ast::Pat::cast).any(|pat| match pat {
+        ast::Pat::LiteralPat(_) | ast::Pat::RangePat(_) => true,
+        ast::Pat::IdentPat(it) => {
+            sema.resolve_bind_pat_to_const(&it).map_or(false, is_refutable_def)
+        }
+        ast::Pat::PathPat(it) => is_refutable_path(it.path()),
+        ast::Pat::RecordPat(it) => is_refutable_path(it.path()),
+        ast::Pat::TupleStructPat(it) => is_refutable_path(it.path()),
+        _ => false,
+    })
+}
+
 /// Attempts to find `node` inside `syntax` via `node`'s text range.
 /// If the fake identifier has been inserted after this node or inside of this node use the `_compensated` version instead.
 fn find_opt_node_in_file<N: AstNode>(syntax: &SyntaxNode, node: Option<N>) -> Option<N> {
@@ -1,11 +1,15 @@
 //! See `CompletionItem` structure.
 
-use std::{fmt, mem};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+};
 
 use hir::Mutability;
 use ide_db::{
-    documentation::Documentation, imports::import_assets::LocatedImport, RootDatabase, SnippetCap,
-    SymbolKind,
+    defs::Definition, documentation::Documentation, imports::import_assets::LocatedImport,
+    FxHasher, RootDatabase, SnippetCap, SymbolKind,
 };
 use itertools::Itertools;
 use smallvec::SmallVec;
@@ -47,6 +51,8 @@ pub struct CompletionItem {
 
     /// What item (struct, function, etc) are we completing.
     pub kind: CompletionItemKind,
+    /// Broad group this item belongs to, for clients that present completions grouped.
+    pub category: CompletionItemCategory,
 
     /// Lookup is used to check if completion item indeed can complete current
     /// ident.
@@ -62,6 +68,9 @@ pub struct CompletionItem {
     /// Whether this item is marked as deprecated
     pub deprecated: bool,
 
+    /// Whether this item introduces a binding that shadows a local already in scope.
+    pub shadows_local: bool,
+
     /// If completing a function call, ask the editor to show parameter popup
     /// after completion.
     pub trigger_call_info: bool,
@@ -74,6 +83,10 @@ pub struct CompletionItem {
     /// all possible items, and then separately build an ordered completion list
     /// based on relevance and fuzzy matching with the already typed identifier.
     pub relevance: CompletionRelevance,
+    /// The score the fuzzy matcher assigned when matching `lookup` against the already typed
+    /// identifier, higher meaning a better match. `None` if nothing has been typed yet or the item
+    /// does not match it.
+    pub fuzzy_score: Option<u32>,
 
     /// Indicates that a reference or mutable reference to this variable is a
     /// possible match.
@@ -85,6 +98,39 @@ pub struct CompletionItem {
     /// The import data to add to completion's edits.
     /// (ImportPath, LastSegment)
     pub import_to_add: SmallVec<[(String, String); 1]>,
+
+    /// Identity of the definition this item completes, if it completes one.
+    pub id: Option<CompletionId>,
+}
+
+/// Broad group of a [`CompletionItem`], derived from where the item originates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompletionItemCategory {
+    /// Local bindings in scope.
+    Local,
+    /// Items that are only reachable by adding an import.
+    Import,
+    /// Snippets, including postfix and user-defined ones.
+    Snippet,
+    /// Keywords.
+    Keyword,
+    /// Everything else, for instance items already in scope or members of a type.
+    Other,
+}
+
+/// Identifies the definition a [`CompletionItem`] was created for.
+///
+/// The id is derived from interned ids, so it is only stable within a single database revision:
+/// clients can use it to correlate and cache resolved item details until the next change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompletionId(u64);
+
+impl CompletionId {
+    fn new(def: Definition, kind: CompletionItemKind) -> CompletionId {
+        let mut hasher = FxHasher::default();
+        (def, kind).hash(&mut hasher);
+        CompletionId(hasher.finish())
+    }
 }
 
 // We use custom debug for CompletionItem to make snapshot tests more readable.
@@ -112,6 +158,9 @@ impl fmt::Debug for CompletionItem {
         if self.deprecated {
             s.field("deprecated", &true);
         }
+        if self.shadows_local {
+            s.field("shadows_local", &true);
+        }
 
         if self.relevance != CompletionRelevance::default() {
             s.field("relevance", &self.relevance);
@@ -156,10 +205,36 @@ pub struct CompletionRelevance {
     pub is_name_already_imported: bool,
     /// This is set for completions that will insert a `use` item.
     pub requires_import: bool,
+    /// This is set for completions that will insert a `use` item with a very long path, like a
+    /// deeply nested re-export, where a shorter path is usually the more idiomatic choice.
+    pub is_long_import_path: bool,
+    /// This is set when the type of the completion implements the trait bounds of the generic
+    /// parameter that is expected:
+    ///
+    /// ```
+    /// fn f(x: impl Display) {}
+    /// fn main {
+    ///     let shown = 0;
+    ///     f($0) // type of local implements `Display`
+    /// }
+    /// ```
+    pub satisfies_expected_bounds: bool,
+    /// This is set for test related attributes like `#[test]` when completing inside of a
+    /// `#[cfg(test)]` module.
+    pub is_test_attribute: bool,
     /// Set for method completions of the `core::ops` and `core::cmp` family.
     pub is_op_method: bool,
     /// Set for item completions that are private but in the workspace.
     pub is_private_editable: bool,
+    /// Set for items that are `#[doc(hidden)]` or defined in a `#[doc(hidden)]` module.
+    pub is_doc_hidden: bool,
+    /// This is set for fields completed in method call position, where a method
+    /// of the same name is the more likely target:
+    ///
+    /// ```
+    /// s.len$0() // prefer the `len` method over a callable `len` field
+    /// ```
+    pub is_field_in_call_position: bool,
     /// Set for postfix snippet item completions
     pub postfix_match: Option<CompletionRelevancePostfixMatch>,
     /// This is set for type inference results
@@ -181,6 +256,16 @@ pub enum CompletionRelevanceTypeMatch {
     /// This is set in cases like these:
     ///
     /// ```
+    /// fn f(spam: &str) {}
+    /// fn main {
+    ///     let foo = &String::new();
+    ///     f($0) // type of local coerces to the type of param
+    /// }
+    /// ```
+    Coercible,
+    /// This is set in cases like these:
+    ///
+    /// ```
     /// fn f(spam: String) {}
     /// fn main {
     ///     let foo = String::new();
@@ -224,8 +309,13 @@ impl CompletionRelevance {
             is_item_from_trait,
             is_name_already_imported,
             requires_import,
+            is_long_import_path,
+            satisfies_expected_bounds,
+            is_test_attribute,
             is_op_method,
             is_private_editable,
+            is_doc_hidden,
+            is_field_in_call_position,
             postfix_match,
             is_definite,
         } = self;
@@ -234,6 +324,14 @@ impl CompletionRelevance {
         if !is_private_editable {
             score += 1;
         }
+        // lower rank doc hidden things
+        if !is_doc_hidden {
+            score += 1;
+        }
+        // lower rank fields when a method call is being written
+        if !is_field_in_call_position {
+            score += 1;
+        }
         // lower rank trait op methods
         if !is_op_method {
             score += 10;
@@ -246,6 +344,10 @@ impl CompletionRelevance {
         if !requires_import {
             score += 1;
         }
+        // lower rank for items imported through very long paths
+        if !is_long_import_path {
+            score += 1;
+        }
         if exact_name_match {
             score += 10;
         }
@@ -256,13 +358,20 @@ impl CompletionRelevance {
         };
         score += match type_match {
             Some(CompletionRelevanceTypeMatch::Exact) => 8,
+            Some(CompletionRelevanceTypeMatch::Coercible) => 5,
             Some(CompletionRelevanceTypeMatch::CouldUnify) => 3,
             None => 0,
         };
+        if satisfies_expected_bounds {
+            score += 2;
+        }
         // slightly prefer locals
         if is_local {
             score += 1;
         }
+        if is_test_attribute {
+            score += 1;
+        }
         if is_item_from_trait {
             score += 1;
         }
@@ -281,7 +390,7 @@ impl CompletionRelevance {
 }
 
 /// The type of the completion item.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum CompletionItemKind {
     SymbolKind(SymbolKind),
     Binding,
@@ -352,17 +461,20 @@ impl CompletionItem {
             insert_text: None,
             is_snippet: false,
             trait_name: None,
+            deref_owner: None,
             detail: None,
             documentation: None,
             lookup: None,
             kind: kind.into(),
             text_edit: None,
             deprecated: false,
+            shadows_local: false,
             trigger_call_info: false,
             relevance: CompletionRelevance::default(),
             ref_match: None,
             imports_to_add: Default::default(),
             doc_aliases: vec![],
+            def: None,
         }
     }
 
@@ -395,6 +507,7 @@ pub(crate) struct Builder {
     source_range: TextRange,
     imports_to_add: SmallVec<[LocatedImport; 1]>,
     trait_name: Option<SmolStr>,
+    deref_owner: Option<SmolStr>,
     doc_aliases: Vec<SmolStr>,
     label: SmolStr,
     insert_text: Option<String>,
@@ -405,9 +518,11 @@ pub(crate) struct Builder {
     kind: CompletionItemKind,
     text_edit: Option<TextEdit>,
     deprecated: bool,
+    shadows_local: bool,
     trigger_call_info: bool,
     relevance: CompletionRelevance,
     ref_match: Option<(Mutability, TextSize)>,
+    def: Option<Definition>,
 }
 
 impl Builder {
@@ -469,12 +584,32 @@ impl Builder {
                 label_detail.as_deref().unwrap_or_default(),
             )));
         }
+        if let Some(deref_owner) = self.deref_owner {
+            label_detail.replace(SmolStr::from(format!(
+                "{} (via {deref_owner})",
+                label_detail.as_deref().unwrap_or_default(),
+            )));
+        }
 
         let text_edit = match self.text_edit {
             Some(it) => it,
             None => TextEdit::replace(self.source_range, insert_text),
         };
 
+        let category = if !self.imports_to_add.is_empty() {
+            CompletionItemCategory::Import
+        } else {
+            match self.kind {
+                CompletionItemKind::Binding
+                | CompletionItemKind::SymbolKind(
+                    SymbolKind::Local | SymbolKind::SelfParam | SymbolKind::ValueParam,
+                ) => CompletionItemCategory::Local,
+                CompletionItemKind::Snippet => CompletionItemCategory::Snippet,
+                CompletionItemKind::Keyword => CompletionItemCategory::Keyword,
+                _ => CompletionItemCategory::Other,
+            }
+        };
+
         let import_to_add = self
             .imports_to_add
             .into_iter()
@@ -496,11 +631,15 @@ impl Builder {
             documentation: self.documentation,
             lookup,
             kind: self.kind,
+            category,
             deprecated: self.deprecated,
+            shadows_local: self.shadows_local,
             trigger_call_info: self.trigger_call_info,
             relevance: self.relevance,
+            fuzzy_score: None,
             ref_match: self.ref_match,
             import_to_add,
+            id: self.def.map(|def| CompletionId::new(def, self.kind)),
         }
     }
     pub(crate) fn lookup_by(&mut self, lookup: impl Into<SmolStr>) -> &mut Builder {
@@ -515,6 +654,11 @@ impl Builder {
         self.trait_name = Some(trait_name);
         self
     }
+    /// Marks the item as reached through a deref of the receiver to the type named `deref_owner`.
+    pub(crate) fn deref_owner(&mut self, deref_owner: SmolStr) -> &mut Builder {
+        self.deref_owner = Some(deref_owner);
+        self
+    }
     pub(crate) fn doc_aliases(&mut self, doc_aliases: Vec<SmolStr>) -> &mut Builder {
         self.doc_aliases = doc_aliases;
         self
@@ -564,6 +708,14 @@ impl Builder {
         self.deprecated = deprecated;
         self
     }
+    pub(crate) fn set_shadows_local(&mut self, shadows_local: bool) -> &mut Builder {
+        self.shadows_local = shadows_local;
+        self
+    }
+    pub(crate) fn set_def(&mut self, def: impl Into<Definition>) -> &mut Builder {
+        self.def = Some(def.into());
+        self
+    }
     pub(crate) fn set_relevance(&mut self, relevance: CompletionRelevance) -> &mut Builder {
         self.relevance = relevance;
         self
@@ -645,10 +797,17 @@ mod tests {
             vec![Cr { is_op_method: true, is_private_editable: true, ..default }],
             vec![Cr { is_op_method: true, ..default }],
             vec![Cr { postfix_match: Some(CompletionRelevancePostfixMatch::NonExact), ..default }],
-            vec![Cr { is_private_editable: true, ..default }],
+            vec![
+                Cr { is_private_editable: true, ..default },
+                Cr { is_doc_hidden: true, ..default },
+                Cr { is_field_in_call_position: true, ..default },
+                Cr { is_long_import_path: true, ..default },
+            ],
             vec![default],
-            vec![Cr { is_local: true, ..default }],
+            vec![Cr { is_local: true, ..default }, Cr { is_test_attribute: true, ..default }],
+            vec![Cr { satisfies_expected_bounds: true, ..default }],
             vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::CouldUnify), ..default }],
+            vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::Coercible), ..default }],
             vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::Exact), ..default }],
             vec![Cr { exact_name_match: true, ..default }],
             vec![Cr { exact_name_match: true, is_local: true, ..default }],
@@ -668,4 +827,19 @@ mod tests {
 
         check_relevance_score_ordered(expected_relevance_order);
     }
+
+    #[test]
+    fn type_match_relevance_order() {
+        use CompletionRelevanceTypeMatch as Tm;
+        let type_match = |type_match| CompletionRelevance {
+            type_match: Some(type_match),
+            ..CompletionRelevance::default()
+        };
+        check_relevance_score_ordered(vec![
+            vec![CompletionRelevance::default()],
+            vec![type_match(Tm::CouldUnify)],
+            vec![type_match(Tm::Coercible)],
+            vec![type_match(Tm::Exact)],
+        ]);
+    }
 }
@@ -21,7 +21,7 @@ use ide_db::{
     },
     items_locator, RootDatabase,
 };
-use syntax::algo;
+use syntax::{algo, AstToken};
 use text_edit::TextEdit;
 
 use crate::{
@@ -35,7 +35,8 @@ use crate::{
 pub use crate::{
     config::{CallableSnippets, CompletionConfig},
     item::{
-        CompletionItem, CompletionItemKind, CompletionRelevance, CompletionRelevancePostfixMatch,
+        CompletionId, CompletionItem, CompletionItemCategory, CompletionItemKind,
+        CompletionRelevance, CompletionRelevancePostfixMatch,
     },
     snippet::{Snippet, SnippetScope},
 };
@@ -207,6 +208,16 @@ pub fn completions(
                 completions::extern_abi::complete_extern_abi(acc, ctx, expanded);
                 completions::format_string::format_string(acc, ctx, original, expanded);
                 completions::env_vars::complete_cargo_env_vars(acc, ctx, expanded);
+                completions::escape::complete_escape_sequences(acc, ctx, original.syntax());
+            }
+            CompletionAnalysis::DocComment(comment) => {
+                completions::doc_link::complete_doc_link(acc, ctx, comment);
+            }
+            CompletionAnalysis::IntNumber(number) => {
+                completions::literal_suffix::complete_int_suffix(acc, ctx, number);
+            }
+            CompletionAnalysis::Char(literal) => {
+                completions::escape::complete_escape_sequences(acc, ctx, literal.syntax());
             }
             CompletionAnalysis::UnexpandedAttrTT {
                 colon_prefix,
@@ -225,7 +236,13 @@ pub fn completions(
         }
     }
 
-    Some(completions.into())
+    let mut completions: Vec<CompletionItem> = completions.into();
+    if let Some(typed) = ctx.typed_name() {
+        for item in &mut completions {
+            item.fuzzy_score = context::fuzzy_match_score(typed, item.lookup());
+        }
+    }
+    Some(completions)
 }
 
 /// Resolves additional completion data at the position given.
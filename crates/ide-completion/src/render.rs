@@ -12,6 +12,7 @@ pub(crate) mod literal;
 
 use hir::{AsAssocItem, HasAttrs, HirDisplay, ModuleDef, ScopeDef, Type};
 use ide_db::{
+    defs::Definition,
     documentation::{Documentation, HasDocs},
     helpers::item_name,
     imports::import_assets::LocatedImport,
@@ -80,6 +81,7 @@ impl<'a> RenderContext<'a> {
         CompletionRelevance {
             is_private_editable: self.is_private_editable,
             requires_import: self.import_to_add.is_some(),
+            is_long_import_path: is_long_import_path(self.import_to_add.as_ref()),
             ..Default::default()
         }
     }
@@ -129,6 +131,7 @@ pub(crate) fn render_field(
     receiver: Option<hir::Name>,
     field: hir::Field,
     ty: &hir::Type,
+    via_deref: bool,
 ) -> CompletionItem {
     let db = ctx.db();
     let is_deprecated = ctx.is_deprecated(field);
@@ -141,13 +144,22 @@ pub(crate) fn render_field(
     );
     item.set_relevance(CompletionRelevance {
         type_match: compute_type_match(ctx.completion, ty),
+        satisfies_expected_bounds: compute_satisfies_expected_bounds(ctx.completion, ty),
         exact_name_match: compute_exact_name_match(ctx.completion, name.as_str()),
+        is_field_in_call_position: matches!(
+            dot_access.kind,
+            DotAccessKind::Method { has_parens: true }
+        ),
         ..CompletionRelevance::default()
     });
     item.detail(ty.display(db).to_string())
         .set_documentation(field.docs(db))
         .set_deprecated(is_deprecated)
+        .set_def(field)
         .lookup_by(name);
+    if via_deref {
+        item.deref_owner(field.parent_def(db).name(db).to_smol_str());
+    }
 
     let is_field_access = matches!(dot_access.kind, DotAccessKind::Field { .. });
     if !is_field_access || ty.is_fn() || ty.is_closure() {
@@ -206,6 +218,51 @@ fn field_with_receiver(
     )
 }
 
+/// Renders `field` of a record literal twice: once using the field init shorthand, which relies on
+/// the local of the same name and is ranked first, and once in its explicit `field: value` form.
+pub(crate) fn render_field_shorthand(
+    ctx: RenderContext<'_>,
+    field: hir::Field,
+    ty: &hir::Type,
+    local: hir::Local,
+) -> [CompletionItem; 2] {
+    let db = ctx.db();
+    let is_deprecated = ctx.is_deprecated(field);
+    let name = field.name(db);
+    let (name, escaped_name) = (name.unescaped().to_smol_str(), name.to_smol_str());
+    let docs = field.docs(db);
+    let detail = ty.display(db).to_string();
+
+    let mut shorthand = CompletionItem::new(SymbolKind::Field, ctx.source_range(), name.clone());
+    shorthand
+        .set_relevance(CompletionRelevance {
+            type_match: match_types(ctx.completion, ty, &local.ty(db)),
+            exact_name_match: true,
+            is_local: true,
+            ..ctx.completion_relevance()
+        })
+        .detail(detail.clone())
+        .set_documentation(docs.clone())
+        .set_deprecated(is_deprecated)
+        .lookup_by(name.clone())
+        .insert_text(escaped_name.as_str());
+
+    let mut explicit =
+        CompletionItem::new(SymbolKind::Field, ctx.source_range(), format!("{name}: …"));
+    explicit
+        .set_relevance(ctx.completion_relevance())
+        .detail(detail)
+        .set_documentation(docs)
+        .set_deprecated(is_deprecated)
+        .lookup_by(name);
+    match ctx.snippet_cap() {
+        Some(cap) => explicit.insert_snippet(cap, format!("{escaped_name}: $0")),
+        None => explicit.insert_text(format!("{escaped_name}: ")),
+    };
+
+    [shorthand.build(db), explicit.build(db)]
+}
+
 pub(crate) fn render_tuple_field(
     ctx: RenderContext<'_>,
     receiver: Option<hir::Name>,
@@ -341,6 +398,7 @@ fn render_resolution_path(
     let db = completion.db;
     let config = completion.config;
     let requires_import = import_to_add.is_some();
+    let is_long_import_path = is_long_import_path(import_to_add.as_ref());
 
     let name = local_name.to_smol_str();
     let mut item = render_resolution_simple_(ctx, &local_name, import_to_add, resolution);
@@ -379,9 +437,12 @@ fn render_resolution_path(
 
         item.set_relevance(CompletionRelevance {
             type_match: compute_type_match(completion, &ty),
+            satisfies_expected_bounds: compute_satisfies_expected_bounds(completion, &ty),
             exact_name_match: compute_exact_name_match(completion, &name),
             is_local: matches!(resolution, ScopeDef::Local(_)),
             requires_import,
+            is_long_import_path,
+            is_doc_hidden: scope_def_is_doc_hidden(completion, resolution),
             ..CompletionRelevance::default()
         });
 
@@ -429,9 +490,16 @@ fn render_resolution_simple_(
 
     let mut item =
         CompletionItem::new(kind, ctx.source_range(), local_name.unescaped().to_smol_str());
-    item.set_relevance(ctx.completion_relevance())
+    let relevance = CompletionRelevance {
+        is_doc_hidden: scope_def_is_doc_hidden(ctx.completion, resolution),
+        ..ctx.completion_relevance()
+    };
+    item.set_relevance(relevance)
         .set_documentation(scope_def_docs(db, resolution))
         .set_deprecated(scope_def_is_deprecated(&ctx, resolution));
+    if let Some(def) = scope_def_to_def(resolution) {
+        item.set_def(def);
+    }
 
     if let Some(import_to_add) = ctx.import_to_add {
         item.add_import(import_to_add);
@@ -441,7 +509,7 @@ fn render_resolution_simple_(
     item
 }
 
-fn res_to_kind(resolution: ScopeDef) -> CompletionItemKind {
+pub(crate) fn res_to_kind(resolution: ScopeDef) -> CompletionItemKind {
     use hir::ModuleDef::*;
     match resolution {
         ScopeDef::Unknown => CompletionItemKind::UnresolvedReference,
@@ -498,7 +566,35 @@ fn scope_def_is_deprecated(ctx: &RenderContext<'_>, resolution: ScopeDef) -> boo
     }
 }
 
-// FIXME: This checks types without possible coercions which some completions might want to do
+fn scope_def_to_def(resolution: ScopeDef) -> Option<Definition> {
+    let def = match resolution {
+        ScopeDef::ModuleDef(it) => it.into(),
+        ScopeDef::GenericParam(it) => it.into(),
+        ScopeDef::Local(it) => it.into(),
+        ScopeDef::Label(it) => it.into(),
+        ScopeDef::AdtSelfType(_) | ScopeDef::ImplSelfType(_) | ScopeDef::Unknown => return None,
+    };
+    Some(def)
+}
+
+fn scope_def_is_doc_hidden(ctx: &CompletionContext<'_>, resolution: ScopeDef) -> bool {
+    let ScopeDef::ModuleDef(def) = resolution else { return false };
+    def.attrs(ctx.db).map_or(false, |attrs| attrs.has_doc_hidden())
+        || def.module(ctx.db).map_or(false, |module| ctx.is_module_doc_hidden(module))
+}
+
+/// Imports with more segments than this are considered unidiomatic, `std::collections::HashMap`
+/// still fits.
+///
+/// This is a fixed threshold rather than a comparison against the other candidates, as each
+/// located import is rendered on its own without knowing about its siblings. The penalty is only
+/// a single point of relevance, so it merely breaks ties between otherwise equal candidates.
+const LONG_IMPORT_PATH_LEN: usize = 3;
+
+fn is_long_import_path(import: Option<&LocatedImport>) -> bool {
+    import.map_or(false, |it| it.import_path.len() > LONG_IMPORT_PATH_LEN)
+}
+
 fn match_types(
     ctx: &CompletionContext<'_>,
     ty1: &hir::Type,
@@ -508,6 +604,8 @@ fn match_types(
         Some(CompletionRelevanceTypeMatch::Exact)
     } else if ty1.could_unify_with(ctx.db, ty2) {
         Some(CompletionRelevanceTypeMatch::CouldUnify)
+    } else if ty2.could_coerce_to(ctx.db, ty1) {
+        Some(CompletionRelevanceTypeMatch::Coercible)
     } else {
         None
     }
@@ -528,6 +626,18 @@ fn compute_type_match(
     match_types(ctx, expected_type, completion_ty)
 }
 
+fn compute_satisfies_expected_bounds(
+    ctx: &CompletionContext<'_>,
+    completion_ty: &hir::Type,
+) -> bool {
+    !ctx.expected_trait_bounds.is_empty()
+        && !completion_ty.is_unknown()
+        && ctx
+            .expected_trait_bounds
+            .iter()
+            .all(|&trait_| completion_ty.impls_trait(ctx.db, trait_, &[]))
+}
+
 fn compute_exact_name_match(ctx: &CompletionContext<'_>, completion_name: &str) -> bool {
     ctx.expected_name.as_ref().map_or(false, |name| name.text() == completion_name)
 }
@@ -654,14 +764,23 @@ mod tests {
                     relevance.type_match == Some(CompletionRelevanceTypeMatch::CouldUnify),
                     "type_could_unify",
                 ),
+                (
+                    relevance.type_match == Some(CompletionRelevanceTypeMatch::Coercible),
+                    "type_coercible",
+                ),
                 (relevance.exact_name_match, "name"),
                 (relevance.is_local, "local"),
+                (relevance.satisfies_expected_bounds, "bounds"),
+                (relevance.is_test_attribute, "test_attr"),
                 (
                     relevance.postfix_match == Some(CompletionRelevancePostfixMatch::Exact),
                     "snippet",
                 ),
                 (relevance.is_op_method, "op_method"),
                 (relevance.requires_import, "requires_import"),
+                (relevance.is_long_import_path, "long_import_path"),
+                (relevance.is_doc_hidden, "doc_hidden"),
+                (relevance.is_field_in_call_position, "field_in_call"),
             ]
             .into_iter()
             .filter_map(|(cond, desc)| if cond { Some(desc) } else { None })
@@ -704,6 +823,63 @@ fn main() {
         );
     }
 
+    #[test]
+    fn short_import_path_ranked_above_long_one() {
+        check_relevance_for_kinds(
+            r#"
+//- /lib.rs crate:dep
+pub mod collections {
+    pub struct HashMap;
+}
+
+pub mod deeply {
+    pub mod nested {
+        pub mod module {
+            pub struct HashMap;
+        }
+    }
+}
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    HashMa$0
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Struct)],
+            expect![[r#"
+                st HashMap (use dep::collections::HashMap) [requires_import]
+                st HashMap (use dep::deeply::nested::module::HashMap) [requires_import+long_import_path]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn value_satisfying_expected_bounds_ranked_higher() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+trait Display {}
+struct Shown;
+impl Display for Shown {}
+struct Hidden;
+
+fn show(_: impl Display) {}
+
+fn main() {
+    let shown = Shown;
+    let hidden = Hidden;
+    show($0);
+}
+"#,
+            None,
+        );
+        let relevance_of =
+            |label: &str| items.iter().find(|it| it.label == label).unwrap().relevance;
+        assert!(relevance_of("shown").satisfies_expected_bounds);
+        assert!(!relevance_of("hidden").satisfies_expected_bounds);
+        assert!(relevance_of("shown").score() > relevance_of("hidden").score());
+    }
+
     #[test]
     fn set_union_type_completion_info() {
         check_relevance(
@@ -1172,8 +1348,13 @@ fn main() { let _: m::Spam = S$0 }
                             is_item_from_trait: false,
                             is_name_already_imported: false,
                             requires_import: false,
+                            is_long_import_path: false,
+                            satisfies_expected_bounds: false,
+                            is_test_attribute: false,
                             is_op_method: false,
                             is_private_editable: false,
+                            is_doc_hidden: false,
+                            is_field_in_call_position: false,
                             postfix_match: None,
                             is_definite: false,
                         },
@@ -1198,8 +1379,13 @@ fn main() { let _: m::Spam = S$0 }
                             is_item_from_trait: false,
                             is_name_already_imported: false,
                             requires_import: false,
+                            is_long_import_path: false,
+                            satisfies_expected_bounds: false,
+                            is_test_attribute: false,
                             is_op_method: false,
                             is_private_editable: false,
+                            is_doc_hidden: false,
+                            is_field_in_call_position: false,
                             postfix_match: None,
                             is_definite: false,
                         },
@@ -1276,8 +1462,13 @@ fn foo() { A { the$0 } }
                             is_item_from_trait: false,
                             is_name_already_imported: false,
                             requires_import: false,
+                            is_long_import_path: false,
+                            satisfies_expected_bounds: false,
+                            is_test_attribute: false,
                             is_op_method: false,
                             is_private_editable: false,
+                            is_doc_hidden: false,
+                            is_field_in_call_position: false,
                             postfix_match: None,
                             is_definite: false,
                         },
@@ -1589,6 +1780,80 @@ fn foo(a: A) { B { bar: a.$0 }; }
         )
     }
 
+    #[test]
+    fn conversion_adapter_relevance() {
+        cov_mark::check!(conversion_adapter_type_match);
+        check_relevance(
+            r#"
+//- minicore: as_ref
+struct String;
+impl String {
+    fn as_str(&self) -> &str { "" }
+}
+impl AsRef<str> for String {
+    fn as_ref(&self) -> &str { "" }
+}
+fn takes(s: &str) {}
+fn main() {
+    let string = String;
+    takes(string.$0);
+}
+"#,
+            expect![[r#"
+                me as_str() [type]
+                me as_ref() (as AsRef) [type]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn record_field_shorthand_ranked_first() {
+        check_relevance(
+            r#"
+struct S { x: i32, y: i32 }
+fn foo() {
+    let x: i32 = 92;
+    S { $0 };
+}
+"#,
+            expect![[r#"
+                fd x [type+name+local]
+                fd x: … []
+                fd y []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn record_field_shorthand_requires_fitting_local() {
+        check_relevance(
+            r#"
+struct S { x: i32, y: i32 }
+fn foo() {
+    let x = "";
+    S { $0 };
+}
+"#,
+            expect![[r#"
+                fd x []
+                fd y []
+            "#]],
+        );
+        check_relevance(
+            r#"
+union U { x: i32, y: i32 }
+fn foo() {
+    let x: i32 = 92;
+    U { $0 };
+}
+"#,
+            expect![[r#"
+                fd x []
+                fd y []
+            "#]],
+        );
+    }
+
     #[test]
     fn record_field_and_call_relevances() {
         check_relevance(
@@ -1923,6 +2188,73 @@ fn bar(t: &Foo) {}
         );
     }
 
+    #[test]
+    fn default_call_relevance() {
+        check_relevance(
+            r#"
+//- minicore: default
+struct Foo;
+impl Default for Foo {
+    fn default() -> Self { Foo }
+}
+fn take(foo: Foo) {}
+fn main() {
+    take($0);
+}
+"#,
+            expect![[r#"
+                st Foo [type]
+                fn Foo::default() [type]
+                st Foo [type]
+                fn main() []
+                fn take(…) []
+                md core []
+                tt Default []
+            "#]],
+        );
+        check_relevance(
+            r#"
+//- minicore: default
+struct Foo;
+impl Default for Foo {
+    fn default() -> Self { Foo }
+}
+fn take(foo: &Foo) {}
+fn main() {
+    take($0);
+}
+"#,
+            expect![[r#"
+                st Foo []
+                st &Foo [type]
+                st Foo []
+                st &Foo [type]
+                fn main() []
+                fn take(…) []
+                md core []
+                tt Default []
+            "#]],
+        );
+        check_relevance(
+            r#"
+//- minicore: default
+struct Foo;
+fn take(foo: Foo) {}
+fn main() {
+    take($0);
+}
+"#,
+            expect![[r#"
+                st Foo [type]
+                st Foo [type]
+                fn main() []
+                fn take(…) []
+                md core []
+                tt Default []
+            "#]],
+        );
+    }
+
     #[test]
     fn suggest_deref_fn_ret() {
         check_relevance(
@@ -2091,8 +2423,13 @@ fn foo() {
                             is_item_from_trait: false,
                             is_name_already_imported: false,
                             requires_import: false,
+                            is_long_import_path: false,
+                            satisfies_expected_bounds: false,
+                            is_test_attribute: false,
                             is_op_method: false,
                             is_private_editable: false,
+                            is_doc_hidden: false,
+                            is_field_in_call_position: false,
                             postfix_match: None,
                             is_definite: false,
                         },
@@ -2169,6 +2506,132 @@ fn foo() {
         );
     }
 
+    #[test]
+    fn bool_literals_ranked_for_expected_bool() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+fn f(b: bool) {}
+fn main() {
+    let x = 1u32;
+    f($0)
+}
+"#,
+            None,
+        );
+        let score = |label: &str| {
+            items.iter().find(|it| it.label == label).map(|it| it.relevance.score()).unwrap()
+        };
+        assert!(score("true") > score("x"));
+        assert!(score("false") > score("x"));
+    }
+
+    #[test]
+    fn error_variants_ranked_in_err_position() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+//- minicore: result
+enum MyError { NotFound, Other }
+struct Unrelated;
+fn unrelated() -> Unrelated { Unrelated }
+fn f() -> Result<(), MyError> {
+    Err($0)
+}
+"#,
+            None,
+        );
+        let relevance =
+            |label: &str| items.iter().find(|it| it.label == label).map(|it| it.relevance).unwrap();
+        for variant in ["MyError::NotFound", "MyError::Other"] {
+            assert_eq!(relevance(variant).type_match, Some(CompletionRelevanceTypeMatch::Exact));
+            assert!(relevance(variant).score() > relevance("Unrelated").score());
+            assert!(relevance(variant).score() > relevance("unrelated()").score());
+        }
+    }
+
+    #[test]
+    fn exact_type_match_ranked_above_coercible() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+struct S;
+fn foo(s: &S) {}
+fn main() {
+    let exact = &S;
+    let coercible = &mut S;
+    foo($0);
+}
+"#,
+            None,
+        );
+        let relevance =
+            |label: &str| items.iter().find(|it| it.label == label).map(|it| it.relevance).unwrap();
+        assert_eq!(relevance("exact").type_match, Some(CompletionRelevanceTypeMatch::Exact));
+        assert_eq!(
+            relevance("coercible").type_match,
+            Some(CompletionRelevanceTypeMatch::Coercible)
+        );
+        assert!(relevance("exact").score() > relevance("coercible").score());
+    }
+
+    #[test]
+    fn completion_id_is_stable_for_unchanged_code() {
+        let fixture = r#"
+fn foo() {}
+fn main() { f$0 }
+"#;
+        let foo_id = || {
+            get_all_items(TEST_CONFIG, fixture, None)
+                .into_iter()
+                .find(|it| it.label == "foo()")
+                .and_then(|it| it.id)
+        };
+        let id = foo_id();
+        assert!(id.is_some());
+        assert_eq!(id, foo_id());
+    }
+
+    #[test]
+    fn method_ranked_above_field_in_call_position() {
+        check_relevance_for_kinds(
+            r#"
+struct S { len: fn() -> usize }
+impl S { fn len(&self) -> usize { 0 } }
+fn f(s: S) { s.$0(); }
+"#,
+            &[CompletionItemKind::Method, CompletionItemKind::SymbolKind(SymbolKind::Field)],
+            expect![[r#"
+                me len() []
+                fd len [field_in_call]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn doc_hidden_module_items_ranked_lower() {
+        check_relevance_for_kinds(
+            r#"
+#[doc(hidden)]
+pub mod hidden {
+    pub struct Hidden;
+}
+pub mod public {
+    pub struct Public;
+}
+use hidden::Hidden;
+use public::Public;
+
+fn f(_: $0) {}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Struct)],
+            expect![[r#"
+                st Public []
+                st Hidden [doc_hidden]
+            "#]],
+        );
+    }
+
     #[test]
     fn postfix_exact_match_is_high_priority() {
         cov_mark::check!(postfix_exact_match_is_high_priority);
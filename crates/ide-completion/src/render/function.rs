@@ -8,7 +8,10 @@ use syntax::{AstNode, SmolStr};
 
 use crate::{
     context::{CompletionContext, DotAccess, DotAccessKind, PathCompletionCtx, PathKind},
-    item::{Builder, CompletionItem, CompletionItemKind, CompletionRelevance},
+    item::{
+        Builder, CompletionItem, CompletionItemKind, CompletionRelevance,
+        CompletionRelevanceTypeMatch,
+    },
     render::{compute_exact_name_match, compute_ref_match, compute_type_match, RenderContext},
     CallableSnippets,
 };
@@ -73,6 +76,8 @@ fn render(
         call.clone(),
     );
 
+    item.set_def(func);
+
     let ret_type = func.ret_type(db);
     let is_op_method = func
         .as_assoc_item(ctx.db())
@@ -97,12 +102,21 @@ fn render(
         .filter(|_| !has_call_parens)
         .and_then(|cap| Some((cap, params(ctx.completion, func, &func_kind, has_dot_receiver)?)));
 
+    let mut type_match = if has_call_parens || complete_call_parens.is_some() {
+        compute_type_match(completion, &ret_type)
+    } else {
+        compute_type_match(completion, &func.ty(db))
+    };
+    if let FuncKind::Method(DotAccess { receiver_ty: Some(receiver_ty), .. }, _) = &func_kind {
+        if type_match != Some(CompletionRelevanceTypeMatch::Exact)
+            && is_conversion_adapter(completion, &receiver_ty.original, func)
+        {
+            cov_mark::hit!(conversion_adapter_type_match);
+            type_match = Some(CompletionRelevanceTypeMatch::Exact);
+        }
+    }
     item.set_relevance(CompletionRelevance {
-        type_match: if has_call_parens || complete_call_parens.is_some() {
-            compute_type_match(completion, &ret_type)
-        } else {
-            compute_type_match(completion, &func.ty(db))
-        },
+        type_match,
         exact_name_match: compute_exact_name_match(completion, &call),
         is_op_method,
         ..ctx.completion_relevance()
@@ -153,6 +167,43 @@ fn render(
     item
 }
 
+/// Whether `func` is a conversion adapter like `AsRef::as_ref` that turns a receiver not matching
+/// the expected reference type into it.
+fn is_conversion_adapter(
+    ctx: &CompletionContext<'_>,
+    receiver_ty: &hir::Type,
+    func: hir::Function,
+) -> bool {
+    let Some(expected_type) = ctx.expected_type.as_ref() else {
+        return false;
+    };
+    let Some(target) = expected_type.remove_ref() else {
+        return false;
+    };
+    if receiver_ty == expected_type {
+        return false;
+    }
+    let Some(trait_) =
+        func.as_assoc_item(ctx.db).and_then(|it| it.containing_trait_or_trait_impl(ctx.db))
+    else {
+        return false;
+    };
+    let famous_defs = ctx.famous_defs();
+    let adapters = [
+        (famous_defs.core_convert_AsRef(), "as_ref", false),
+        (famous_defs.core_convert_AsMut(), "as_mut", true),
+        (famous_defs.core_borrow_Borrow(), "borrow", false),
+        (famous_defs.core_borrow_BorrowMut(), "borrow_mut", true),
+    ];
+    let name = func.name(ctx.db);
+    adapters.into_iter().any(|(adapter, method, is_mut)| {
+        adapter == Some(trait_)
+            && name.as_str() == Some(method)
+            && expected_type.is_mutable_reference() == is_mut
+            && receiver_ty.impls_trait(ctx.db, trait_, &[target.clone()])
+    })
+}
+
 pub(super) fn add_call_parens<'b>(
     builder: &'b mut Builder,
     ctx: &CompletionContext<'_>,
@@ -60,6 +60,7 @@ fn render(
         label(&ctx, needs_bang, bra, ket, &name),
     );
     item.set_deprecated(ctx.is_deprecated(macro_))
+        .set_def(macro_)
         .detail(macro_.display(completion.db).to_string())
         .set_documentation(docs)
         .set_relevance(ctx.completion_relevance());
@@ -107,6 +108,10 @@ fn banged_name(name: &str) -> SmolStr {
     SmolStr::from_iter([name, "!"])
 }
 
+/// Delimiters conventionally used with well-known macros, consulted when their docs give no hint.
+const KNOWN_MACRO_BRACES: &[(&str, (&str, &str))] =
+    &[("lazy_static", (" {", "}")), ("thread_local", (" {", "}")), ("vec", ("[", "]"))];
+
 fn guess_macro_braces(macro_name: &str, docs: &str) -> (&'static str, &'static str) {
     let mut votes = [0, 0, 0];
     for (idx, s) in docs.match_indices(&macro_name) {
@@ -125,6 +130,13 @@ fn guess_macro_braces(macro_name: &str, docs: &str) -> (&'static str, &'static s
         }
     }
 
+    if votes == [0, 0, 0] {
+        return KNOWN_MACRO_BRACES
+            .iter()
+            .find(|&&(name, _)| name == macro_name)
+            .map_or(("(", ")"), |&(_, braces)| braces);
+    }
+
     // Insert a space before `{}`.
     // We prefer the last one when some votes equal.
     let (_vote, (bra, ket)) = votes
@@ -241,6 +253,32 @@ fn main() { foo! {$0} }
         )
     }
 
+    #[test]
+    fn known_macro_braces_without_docs() {
+        check_edit(
+            "vec!",
+            r#"
+macro_rules! vec { () => {} }
+fn main() { v$0 }
+"#,
+            r#"
+macro_rules! vec { () => {} }
+fn main() { vec![$0] }
+"#,
+        );
+        check_edit(
+            "println!",
+            r#"
+macro_rules! println { () => {} }
+fn main() { p$0 }
+"#,
+            r#"
+macro_rules! println { () => {} }
+fn main() { println!($0) }
+"#,
+        );
+    }
+
     #[test]
     fn completes_macro_call_if_cursor_at_bang_token() {
         // Regression test for https://github.com/rust-lang/rust-analyzer/issues/9904
@@ -7,6 +7,7 @@ use syntax::SmolStr;
 
 use crate::{
     context::{ParamContext, ParamKind, PathCompletionCtx, PatternContext},
+    item::Builder,
     render::{
         variant::{format_literal_label, format_literal_lookup, visible_fields},
         RenderContext,
@@ -36,10 +37,13 @@ pub(crate) fn render_struct_pat(
     let label = format_literal_label(name.as_str(), kind, ctx.snippet_cap());
     let lookup = format_literal_lookup(name.as_str(), kind);
     let pat = render_pat(&ctx, pattern_ctx, &escaped_name, kind, &visible_fields, fields_omitted)?;
+    let shadows_local = binds_shadowing_local(&ctx, kind, &visible_fields);
 
     let db = ctx.db();
 
-    Some(build_completion(ctx, label, lookup, pat, strukt, strukt.ty(db), false))
+    let mut item = build_completion(ctx, label, lookup, pat, strukt, strukt.ty(db), false);
+    item.set_shadows_local(shadows_local);
+    Some(item.build(db))
 }
 
 pub(crate) fn render_variant_pat(
@@ -67,9 +71,9 @@ pub(crate) fn render_variant_pat(
         }
     };
 
-    let (label, lookup, pat) = match path_ctx {
+    let (label, lookup, pat, shadows_local) = match path_ctx {
         Some(PathCompletionCtx { has_call_parens: true, .. }) => {
-            (name.clone(), name, escaped_name.to_string())
+            (name.clone(), name, escaped_name.to_string(), false)
         }
         _ => {
             let kind = variant.kind(ctx.db());
@@ -83,11 +87,14 @@ pub(crate) fn render_variant_pat(
                 &visible_fields,
                 fields_omitted,
             )?;
-            (label, lookup, pat)
+            let shadows_local = binds_shadowing_local(&ctx, kind, &visible_fields);
+            (label, lookup, pat, shadows_local)
         }
     };
 
-    Some(build_completion(
+    let db = ctx.db();
+
+    let mut item = build_completion(
         ctx,
         label,
         lookup,
@@ -95,7 +102,9 @@ pub(crate) fn render_variant_pat(
         variant,
         enum_ty,
         pattern_ctx.missing_variants.contains(&variant),
-    ))
+    );
+    item.set_shadows_local(shadows_local);
+    Some(item.build(db))
 }
 
 fn build_completion(
@@ -107,7 +116,7 @@ fn build_completion(
     adt_ty: hir::Type,
     // Missing in context of match statement completions
     is_variant_missing: bool,
-) -> CompletionItem {
+) -> Builder {
     let mut relevance = ctx.completion_relevance();
 
     if is_variant_missing {
@@ -124,7 +133,13 @@ fn build_completion(
         Some(snippet_cap) => item.insert_snippet(snippet_cap, pat),
         None => item.insert_text(pat),
     };
-    item.build(ctx.db())
+    item
+}
+
+/// Whether the fields bound by a rendered record pattern shadow a local that is in scope.
+fn binds_shadowing_local(ctx: &RenderContext<'_>, kind: StructKind, fields: &[hir::Field]) -> bool {
+    kind == StructKind::Record
+        && fields.iter().any(|field| ctx.completion.is_shadowing_local(&field.name(ctx.db())))
 }
 
 fn render_pat(
@@ -32,12 +32,13 @@ use ide_db::{
 };
 use itertools::Itertools;
 use stdx::{format_to, trim_indent};
+use syntax::TextRange;
 use test_fixture::ChangeFixture;
 use test_utils::assert_eq_text;
 
 use crate::{
-    resolve_completion_edits, CallableSnippets, CompletionConfig, CompletionItem,
-    CompletionItemKind,
+    completions::Completions, resolve_completion_edits, CallableSnippets, CompletionConfig,
+    CompletionItem, CompletionItemCategory, CompletionItemKind,
 };
 
 /// Lots of basic item definitions
@@ -65,11 +66,13 @@ pub(crate) const TEST_CONFIG: CompletionConfig = CompletionConfig {
     enable_imports_on_the_fly: true,
     enable_self_on_the_fly: true,
     enable_private_editable: false,
+    enable_prefilter: false,
     full_function_signatures: false,
     callable: Some(CallableSnippets::FillArguments),
     snippet_cap: SnippetCap::new(true),
     prefer_no_std: false,
     prefer_prelude: true,
+    imports_from_direct_deps_only: false,
     insert_use: InsertUseConfig {
         granularity: ImportGranularity::Crate,
         prefix_kind: PrefixKind::Plain,
@@ -179,6 +182,9 @@ fn render_completion_list(completions: Vec<CompletionItem>) -> String {
             if it.deprecated {
                 format_to!(buf, " DEPRECATED");
             }
+            if it.shadows_local {
+                format_to!(buf, " SHADOWING");
+            }
             format_to!(buf, "\n");
             buf
         })
@@ -318,3 +324,101 @@ Some multi-line comment$0
         String::new(),
     );
 }
+
+#[test]
+fn prefilter_skips_rendering_non_matching_items() {
+    cov_mark::check_count!(prefilter_skips_non_matching_item, 2);
+    let config = CompletionConfig { enable_prefilter: true, ..TEST_CONFIG };
+    let items = get_all_items(
+        config,
+        r#"
+struct S { foo_bar: u32, fizz: u32 }
+impl S {
+    fn fetch_bar(&self) {}
+    fn bar(&self) {}
+}
+fn f(s: S) {
+    s.fb$0
+}
+"#,
+        None,
+    );
+    let labels: Vec<_> = items
+        .iter()
+        .filter(|it| {
+            matches!(
+                it.kind,
+                CompletionItemKind::Method
+                    | CompletionItemKind::SymbolKind(ide_db::SymbolKind::Field)
+            )
+        })
+        .map(|it| it.label.as_str())
+        .sorted()
+        .collect();
+    assert_eq!(labels, ["fetch_bar()", "foo_bar"]);
+}
+
+#[test]
+fn fuzzy_score_prefers_contiguous_prefix_match() {
+    let items = get_all_items(
+        TEST_CONFIG,
+        r#"
+fn foo_bar() {}
+fn fxoxo() {}
+fn main() {
+    fo$0
+}
+"#,
+        None,
+    );
+    let score_of = |label: &str| {
+        items.iter().find(|it| it.label == label).and_then(|it| it.fuzzy_score).unwrap()
+    };
+    assert!(score_of("foo_bar()") > score_of("fxoxo()"));
+}
+
+#[test]
+fn completions_sort_by_label_length() {
+    let db = RootDatabase::default();
+    let mut acc = Completions::default();
+    for label in ["abc", "a", "ab", "b"] {
+        CompletionItem::new(CompletionItemKind::Keyword, TextRange::default(), label)
+            .add_to(&mut acc, &db);
+    }
+    acc.sort_by(|a, b| a.label.len().cmp(&b.label.len()));
+    let labels: Vec<_> = Vec::from(acc).into_iter().map(|it| it.label.to_string()).collect();
+    assert_eq!(labels, ["a", "b", "ab", "abc"]);
+}
+
+#[test]
+fn completions_debug_render() {
+    let db = RootDatabase::default();
+    let mut acc = Completions::default();
+    let mut item = CompletionItem::new(CompletionItemKind::Keyword, TextRange::default(), "fn");
+    item.add_to(&mut acc, &db);
+    let mut item = CompletionItem::new(CompletionItemKind::Method, TextRange::default(), "len()");
+    item.detail("fn(&self) -> usize");
+    item.add_to(&mut acc, &db);
+    expect_test::expect![[r#"
+        kw fn [19]
+        me len() fn(&self) -> usize [19]
+    "#]]
+    .assert_eq(&acc.debug_render());
+}
+
+#[test]
+fn completion_categories() {
+    let items = get_all_items(
+        TEST_CONFIG,
+        r#"
+fn main() {
+    let local = 0;
+    $0
+}
+"#,
+        None,
+    );
+    let category_of = |label: &str| items.iter().find(|it| it.label == label).unwrap().category;
+    assert_eq!(category_of("local"), CompletionItemCategory::Local);
+    assert_eq!(category_of("loop"), CompletionItemCategory::Keyword);
+}
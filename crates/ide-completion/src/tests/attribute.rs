@@ -1,7 +1,7 @@
 //! Completion tests for attributes.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_edit, completion_list};
+use crate::tests::{check_edit, completion_list, get_all_items, TEST_CONFIG};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(ra_fixture);
@@ -66,6 +66,46 @@ struct Foo;
     )
 }
 
+#[test]
+fn tool_attributes() {
+    check(
+        r#"
+#[clippy::$0]
+fn foo() {}
+"#,
+        expect![[r#"
+            at author
+            at cognitive_complexity = "…"
+            at dump
+            at format_args
+            at has_significant_drop
+            at msrv = "…"
+        "#]],
+    );
+    check(
+        r#"
+#[rustfmt::$0]
+fn foo() {}
+"#,
+        expect![[r#"
+            at skip
+            at skip::attributes(…)
+            at skip::macros(…)
+        "#]],
+    );
+    check_edit(
+        "msrv",
+        r#"
+#[clippy::$0]
+fn foo() {}
+"#,
+        r#"
+#[clippy::msrv = "${0:version}"]
+fn foo() {}
+"#,
+    );
+}
+
 #[test]
 fn with_existing_attr() {
     check(
@@ -161,6 +201,89 @@ fn attr_on_module() {
     );
 }
 
+#[test]
+fn inner_attr_on_module_file() {
+    check(
+        r#"
+//- /lib.rs
+mod foo;
+//- /foo.rs
+#![$0]
+"#,
+        expect![[r#"
+            at allow(…)
+            at cfg(…)
+            at cfg_attr(…)
+            at deny(…)
+            at deprecated
+            at doc = "…"
+            at doc(alias = "…")
+            at doc(hidden)
+            at forbid(…)
+            at must_use
+            at no_implicit_prelude
+            at no_mangle
+            at warn(…)
+            kw crate::
+            kw self::
+            kw super::
+        "#]],
+    );
+}
+
+#[test]
+fn feature_only_in_inner_attr() {
+    check(
+        r#"#![fea$0]"#,
+        expect![[r#"
+            at allow(…)
+            at cfg(…)
+            at cfg_attr(…)
+            at crate_name = ""
+            at deny(…)
+            at deprecated
+            at doc = "…"
+            at doc(alias = "…")
+            at doc(hidden)
+            at feature(…)
+            at forbid(…)
+            at must_use
+            at no_implicit_prelude
+            at no_main
+            at no_mangle
+            at no_std
+            at recursion_limit = "…"
+            at type_length_limit = …
+            at warn(…)
+            at windows_subsystem = "…"
+            kw crate::
+            kw self::
+        "#]],
+    );
+    check(
+        r#"#[fea$0] struct Foo;"#,
+        expect![[r#"
+            at allow(…)
+            at cfg(…)
+            at cfg_attr(…)
+            at deny(…)
+            at deprecated
+            at derive(…)
+            at doc = "…"
+            at doc(alias = "…")
+            at doc(hidden)
+            at forbid(…)
+            at must_use
+            at no_mangle
+            at non_exhaustive
+            at repr(…)
+            at warn(…)
+            kw crate::
+            kw self::
+        "#]],
+    );
+}
+
 #[test]
 fn attr_on_macro_rules() {
     check(
@@ -404,7 +527,6 @@ fn attr_on_trait() {
             at doc(hidden)
             at forbid(…)
             at must_use
-            at must_use
             at no_mangle
             at warn(…)
             kw crate::
@@ -539,7 +661,6 @@ fn attr_on_fn() {
             at link_name = "…"
             at link_section = "…"
             at must_use
-            at must_use
             at no_mangle
             at panic_handler
             at proc_macro
@@ -556,6 +677,53 @@ fn attr_on_fn() {
     );
 }
 
+#[test]
+fn test_attrs_ranked_higher_in_test_module() {
+    let fixture = r#"
+#[cfg(test)]
+mod tests {
+    #[$0]
+    fn it_works() {}
+}
+"#;
+    let items = get_all_items(TEST_CONFIG, fixture, None);
+    let is_test_attribute = |label: &str| {
+        items.iter().find(|it| it.label == label).unwrap().relevance.is_test_attribute
+    };
+    assert!(is_test_attribute("test"));
+    assert!(is_test_attribute("should_panic"));
+    assert!(is_test_attribute(r#"ignore = "…""#));
+    assert!(!is_test_attribute("inline"));
+    assert!(items.iter().all(|it| it.label != "bench"));
+
+    let items = get_all_items(TEST_CONFIG, r#"#[$0] fn main() {}"#, None);
+    assert!(items.iter().all(|it| !it.relevance.is_test_attribute));
+}
+
+#[test]
+fn bench_attr_in_test_module_with_feature() {
+    let actual = completion_list(
+        r#"
+#![feature(test)]
+#[cfg(test)]
+mod tests {
+    #[$0]
+    fn bench_it() {}
+}
+"#,
+    );
+    assert!(actual.contains("at bench\n"), "{actual}");
+
+    let actual = completion_list(
+        r#"
+#![feature(test)]
+#[$0]
+fn bench_it() {}
+"#,
+    );
+    assert!(!actual.contains("at bench"), "{actual}");
+}
+
 #[test]
 fn attr_in_source_file_end() {
     check(
@@ -656,6 +824,67 @@ mod cfg {
         );
     }
 
+    #[test]
+    fn inside_cfg_attr() {
+        check(
+            r#"
+//- /main.rs cfg:test,dbg=false,opt_level=2
+#[cfg_attr($0)]
+struct Foo;
+"#,
+            expect![[r#"
+                ba dbg
+                ba opt_level
+                ba test
+            "#]],
+        );
+        check(
+            r#"
+//- /main.rs cfg:test
+#[cfg_attr(test, $0)]
+struct Foo;
+"#,
+            expect![[r#"
+                at allow(…)
+                at automatically_derived
+                at cfg(…)
+                at cfg_attr(…)
+                at cold
+                at deny(…)
+                at deprecated
+                at derive(…)
+                at doc = "…"
+                at doc(alias = "…")
+                at doc(hidden)
+                at export_name = "…"
+                at forbid(…)
+                at global_allocator
+                at ignore = "…"
+                at inline
+                at link
+                at link_name = "…"
+                at link_section = "…"
+                at macro_export
+                at macro_use
+                at must_use
+                at no_mangle
+                at non_exhaustive
+                at panic_handler
+                at path = "…"
+                at proc_macro
+                at proc_macro_attribute
+                at proc_macro_derive(…)
+                at repr(…)
+                at should_panic
+                at target_feature(enable = "…")
+                at test
+                at track_caller
+                at used
+                at warn(…)
+            "#]],
+        );
+    }
+
     #[test]
     fn cfg_target_endian() {
         check(
@@ -828,6 +1057,30 @@ use proc_macros::DeriveIdentity;
         );
     }
 
+    #[test]
+    fn derive_flyimport_from_dependency() {
+        check_edit(
+            "Serialize",
+            r#"
+//- /main.rs crate:main deps:serde
+#[derive(Ser$0)] struct Test;
+//- /serde.rs crate:serde
+#![crate_type = "proc-macro"]
+struct TokenStream;
+
+#[proc_macro_derive(Serialize)]
+pub fn derive_serialize(item: TokenStream) -> TokenStream {
+    item
+}
+"#,
+            r#"
+use serde::Serialize;
+
+#[derive(Serialize)] struct Test;
+"#,
+        );
+    }
+
     #[test]
     fn qualified() {
         check_derive(
@@ -963,8 +1216,8 @@ mod repr {
             expect![[r#"
                 ba C
                 ba align($0)
+                ba i128
                 ba i16
-                ba i28
                 ba i32
                 ba i64
                 ba i8
@@ -992,8 +1245,8 @@ mod repr {
             r#"#[repr(align(1), $0)] struct Test;"#,
             expect![[r#"
                 ba C
+                ba i128
                 ba i16
-                ba i28
                 ba i32
                 ba i64
                 ba i8
@@ -1015,8 +1268,8 @@ mod repr {
             r#"#[repr(packed, $0)] struct Test;"#,
             expect![[r#"
                 ba C
+                ba i128
                 ba i16
-                ba i28
                 ba i32
                 ba i64
                 ba i8
@@ -1038,8 +1291,8 @@ mod repr {
             r#"#[repr(C, $0)] struct Test;"#,
             expect![[r#"
                 ba align($0)
+                ba i128
                 ba i16
-                ba i28
                 ba i32
                 ba i64
                 ba i8
@@ -1066,6 +1319,18 @@ mod repr {
             "#]],
         );
     }
+
+    #[test]
+    fn prim_i128() {
+        check_repr(
+            r#"#[repr(i128, $0)] struct Test;"#,
+            expect![[r#"
+                ba C
+                ba align($0)
+                ba packed
+            "#]],
+        );
+    }
 }
 
 mod macro_use {
@@ -42,6 +42,8 @@ fn baz() {
             un Union         Union
             ev TupleV(…)     TupleV(u32)
             bt u32           u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -56,6 +58,7 @@ fn baz() {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     )
 }
@@ -84,6 +87,8 @@ fn func(param0 @ (param1, param2): (i32, i32)) {
             lc param1     i32
             lc param2     i32
             bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -97,6 +102,7 @@ fn func(param0 @ (param1, param2): (i32, i32)) {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
 }
@@ -135,6 +141,7 @@ impl Unit {
             un Union        Union
             ev TupleV(…)    TupleV(u32)
             bt u32          u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -212,6 +219,7 @@ fn complete_in_block() {
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -257,6 +265,7 @@ fn complete_after_if_expr() {
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw else
@@ -303,8 +312,10 @@ fn complete_in_match_arm() {
     }
 "#,
         expect![[r#"
-            fn foo()     fn()
-            bt u32       u32
+            fn foo()      fn()
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -318,6 +329,7 @@ fn complete_in_match_arm() {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     )
 }
@@ -329,6 +341,7 @@ fn completes_in_loop_ctx() {
         expect![[r#"
             fn my()        fn()
             bt u32         u32
+            kw async
             kw break
             kw const
             kw continue
@@ -369,8 +382,10 @@ fn completes_in_let_initializer() {
     check_empty(
         r#"fn main() { let _ = $0 }"#,
         expect![[r#"
-            fn main()    fn()
-            bt u32       u32
+            fn main()     fn()
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -384,6 +399,7 @@ fn completes_in_let_initializer() {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     )
 }
@@ -402,9 +418,11 @@ fn foo() {
 }
 "#,
         expect![[r#"
-            fn foo()     fn()
-            st Foo       Foo
-            bt u32       u32
+            fn foo()      fn()
+            st Foo        Foo
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -418,6 +436,7 @@ fn foo() {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
 }
@@ -437,9 +456,11 @@ fn foo() {
 }
 "#,
         expect![[r#"
-            fn foo()     fn()
-            lc bar       i32
-            bt u32       u32
+            fn foo()      fn()
+            lc bar        i32
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -453,6 +474,7 @@ fn foo() {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
 }
@@ -467,10 +489,12 @@ fn quux(x: i32) {
 }
 "#,
         expect![[r#"
-            fn quux(…)   fn(i32)
-            lc x         i32
-            ma m!(…)     macro_rules! m
-            bt u32       u32
+            fn quux(…)    fn(i32)
+            lc x          i32
+            ma m!(…)      macro_rules! m
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -484,6 +508,7 @@ fn quux(x: i32) {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
     check_empty(
@@ -494,10 +519,12 @@ fn quux(x: i32) {
 }
 ",
         expect![[r#"
-            fn quux(…)   fn(i32)
-            lc x         i32
-            ma m!(…)     macro_rules! m
-            bt u32       u32
+            fn quux(…)    fn(i32)
+            lc x          i32
+            ma m!(…)      macro_rules! m
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -511,6 +538,7 @@ fn quux(x: i32) {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
     check_empty(
@@ -688,6 +716,8 @@ fn brr() {
             st YoloVariant     YoloVariant
             st YoloVariant {…} YoloVariant { f: usize }
             bt u32             u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -701,6 +731,7 @@ fn brr() {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
 }
@@ -750,6 +781,7 @@ fn foo() { if foo {} $0 }
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw else
@@ -790,6 +822,7 @@ fn foo() { if foo {} el$0 }
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw else
@@ -828,8 +861,10 @@ fn foo() { if foo {} el$0 }
 fn foo() { bar(if foo {} $0) }
 "#,
         expect![[r#"
-            fn foo()     fn()
-            bt u32       u32
+            fn foo()      fn()
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw else
             kw else if
@@ -845,6 +880,7 @@ fn foo() { bar(if foo {} $0) }
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
     check_empty(
@@ -852,8 +888,10 @@ fn foo() { bar(if foo {} $0) }
 fn foo() { bar(if foo {} el$0) }
 "#,
         expect![[r#"
-            fn foo()     fn()
-            bt u32       u32
+            fn foo()      fn()
+            bt u32        u32
+            kw async
+            kw async move
             kw crate::
             kw else
             kw else if
@@ -869,6 +907,7 @@ fn foo() { bar(if foo {} el$0) }
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
     check_empty(
@@ -878,6 +917,7 @@ fn foo() { if foo {} $0 let x = 92; }
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw else
@@ -918,6 +958,7 @@ fn foo() { if foo {} el$0 let x = 92; }
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw else
@@ -958,6 +999,7 @@ fn foo() { if foo {} el$0 { let x = 92; } }
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw else
@@ -993,6 +1035,54 @@ fn foo() { if foo {} el$0 { let x = 92; } }
     );
 }
 
+#[test]
+fn let_else_completion_after_refutable_let() {
+    check_edit(
+        "else",
+        r#"
+//- minicore: option
+fn foo(opt: Option<i32>) {
+    let Some(x) = opt $0
+}
+"#,
+        r#"
+fn foo(opt: Option<i32>) {
+    let Some(x) = opt else {
+    $0
+};
+}
+"#,
+    );
+    check_edit(
+        "else return",
+        r#"
+//- minicore: option
+fn foo(opt: Option<i32>) {
+    let Some(x) = opt $0
+}
+"#,
+        r#"
+fn foo(opt: Option<i32>) {
+    let Some(x) = opt else {
+    return$0;
+};
+}
+"#,
+    );
+}
+
+#[test]
+fn no_let_else_completion_after_irrefutable_let() {
+    let actual = completion_list(
+        r#"
+fn foo(pair: (i32, i32)) {
+    let (a, b) = pair $0
+}
+"#,
+    );
+    assert!(!actual.contains("kw else"));
+}
+
 #[test]
 fn expr_no_unstable_item_on_stable() {
     check_empty(
@@ -1010,6 +1100,7 @@ pub struct UnstableThisShouldNotBeListed;
             fn main()      fn()
             md std
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -1062,6 +1153,7 @@ pub struct UnstableButWeAreOnNightlyAnyway;
             md std
             st UnstableButWeAreOnNightlyAnyway UnstableButWeAreOnNightlyAnyway
             bt u32                    u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -1248,3 +1340,87 @@ fn main() {
         "#]],
     );
 }
+
+#[test]
+fn default_call_for_expected_type() {
+    check_edit(
+        "Foo::default()",
+        r#"
+//- minicore: default
+struct Foo;
+impl Default for Foo {
+    fn default() -> Self { Foo }
+}
+
+fn main() {
+    let foo: Foo = $0;
+}
+"#,
+        r#"
+struct Foo;
+impl Default for Foo {
+    fn default() -> Self { Foo }
+}
+
+fn main() {
+    let foo: Foo = Foo::default();
+}
+"#,
+    );
+}
+
+#[test]
+fn async_block_snippets() {
+    check_edit(
+        "async",
+        r#"fn main() { let _ = $0; }"#,
+        r#"fn main() { let _ = async {
+    $0
+}; }"#,
+    );
+    check_edit(
+        "async move",
+        r#"fn main() { let _ = $0; }"#,
+        r#"fn main() { let _ = async move {
+    $0
+}; }"#,
+    );
+}
+
+#[test]
+fn gen_block_only_with_feature() {
+    let actual = completion_list(
+        r#"
+//- /main.rs edition:2024
+#![feature(gen_blocks)]
+fn main() { let _ = $0; }
+"#,
+    );
+    assert!(actual.contains("kw gen\n"), "{actual}");
+
+    let actual = completion_list(
+        r#"
+//- /main.rs edition:2024
+fn main() { let _ = $0; }
+"#,
+    );
+    assert!(!actual.contains("kw gen\n"), "{actual}");
+}
+
+#[test]
+fn do_yeet_only_with_feature() {
+    let actual = completion_list(
+        r#"
+#![feature(yeet_expr)]
+fn main() { $0 }
+"#,
+    );
+    assert!(actual.contains("kw do yeet\n"), "{actual}");
+
+    let actual = completion_list(
+        r#"
+fn main() { $0 }
+"#,
+    );
+    assert!(!actual.contains("do yeet"), "{actual}");
+}
@@ -3,10 +3,14 @@ use expect_test::{expect, Expect};
 use crate::{
     context::{CompletionAnalysis, NameContext, NameKind, NameRefKind},
     tests::{check_edit, check_edit_with_config, TEST_CONFIG},
+    CompletionConfig,
 };
 
 fn check(ra_fixture: &str, expect: Expect) {
-    let config = TEST_CONFIG;
+    check_with_config(TEST_CONFIG, ra_fixture, expect);
+}
+
+fn check_with_config(config: CompletionConfig, ra_fixture: &str, expect: Expect) {
     let (db, position) = crate::tests::position(ra_fixture);
     let (ctx, analysis) = crate::context::CompletionContext::new(&db, position, &config).unwrap();
 
@@ -1397,3 +1401,32 @@ pub use bridge2::server2::Span2;
         "#]],
     );
 }
+
+#[test]
+fn transitive_dependency_items_excluded_in_direct_deps_only_mode() {
+    let fixture = r#"
+//- /transitive.rs crate:transitive
+pub struct TransitiveThing;
+//- /dep.rs crate:dep deps:transitive
+pub use transitive::TransitiveThing;
+pub struct DirectThing;
+//- /main.rs crate:main deps:dep
+fn main() {
+    Thin$0
+}
+"#;
+    check(
+        fixture,
+        expect![[r#"
+            st DirectThing (use dep::DirectThing) DirectThing
+            st TransitiveThing (use dep::TransitiveThing) TransitiveThing
+        "#]],
+    );
+    check_with_config(
+        CompletionConfig { imports_from_direct_deps_only: true, ..TEST_CONFIG },
+        fixture,
+        expect![[r#"
+            st DirectThing (use dep::DirectThing) DirectThing
+        "#]],
+    );
+}
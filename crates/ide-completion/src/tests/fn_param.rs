@@ -1,6 +1,6 @@
 use expect_test::{expect, Expect};
 
-use crate::tests::{completion_list, completion_list_with_trigger_character};
+use crate::tests::{check_edit, completion_list, completion_list_with_trigger_character};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(ra_fixture);
@@ -166,6 +166,7 @@ impl A {
             bn file_id: usize
             bn mut self
             bn self
+            bn self: Box<Self>
             kw mut
             kw ref
         "#]],
@@ -272,3 +273,44 @@ fn g(foo: (), #[baz = "qux"] mut ba$0)
         "##]],
     )
 }
+
+#[test]
+fn completes_self_receivers_in_first_param() {
+    check(
+        r#"
+struct A {}
+
+impl A {
+    fn foo($0) {}
+}
+"#,
+        expect![[r#"
+            sp Self
+            st A
+            bn &mut self
+            bn &self
+            bn mut self
+            bn self
+            bn self: Box<Self>
+            kw mut
+            kw ref
+        "#]],
+    );
+    check_edit(
+        "&mut self",
+        r#"
+struct A {}
+
+impl A {
+    fn foo($0) {}
+}
+"#,
+        r#"
+struct A {}
+
+impl A {
+    fn foo(&mut self) {}
+}
+"#,
+    );
+}
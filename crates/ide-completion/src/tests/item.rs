@@ -116,6 +116,12 @@ fn completes_where() {
         kw where
     "#]],
     );
+    check(
+        r"fn func<T>() $0{}",
+        expect![[r#"
+        kw where
+    "#]],
+    );
     check(
         r"enum Enum $0",
         expect![[r#"
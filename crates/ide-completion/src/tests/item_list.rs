@@ -14,6 +14,7 @@ fn in_mod_item_list() {
         r#"mod tests { $0 }"#,
         expect![[r#"
             ma makro!(…)           macro_rules! makro
+            kw async
             kw const
             kw crate::
             kw enum
@@ -47,6 +48,7 @@ fn in_source_file_item_list() {
         expect![[r#"
             ma makro!(…)           macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw enum
@@ -79,6 +81,7 @@ fn in_item_list_after_attr() {
         expect![[r#"
             ma makro!(…)           macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw enum
@@ -132,6 +135,7 @@ fn after_visibility() {
     check(
         r#"pub $0"#,
         expect![[r#"
+            kw async
             kw const
             kw enum
             kw extern
@@ -164,8 +168,9 @@ fn in_impl_assoc_item_list() {
     check(
         r#"impl Struct { $0 }"#,
         expect![[r#"
-            ma makro!(…)  macro_rules! makro
+            ma makro!(…)              macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw fn
@@ -174,6 +179,9 @@ fn in_impl_assoc_item_list() {
             kw pub(super)
             kw self::
             kw unsafe
+            sn const (Associated constant)
+            sn fn (Method)
+            sn type (Associated type)
         "#]],
     )
 }
@@ -183,8 +191,9 @@ fn in_impl_assoc_item_list_after_attr() {
     check(
         r#"impl Struct { #[attr] $0 }"#,
         expect![[r#"
-            ma makro!(…)  macro_rules! makro
+            ma makro!(…)              macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw fn
@@ -193,10 +202,52 @@ fn in_impl_assoc_item_list_after_attr() {
             kw pub(super)
             kw self::
             kw unsafe
+            sn const (Associated constant)
+            sn fn (Method)
+            sn type (Associated type)
         "#]],
     )
 }
 
+#[test]
+fn in_impl_assoc_item_list_snippets() {
+    check_edit(
+        "fn (Method)",
+        r#"
+struct Foo;
+impl Foo { $0 }
+"#,
+        r#"
+struct Foo;
+impl Foo { fn $1(&self) {
+    $0
+} }
+"#,
+    );
+    check_edit(
+        "const (Associated constant)",
+        r#"
+struct Foo;
+impl Foo { $0 }
+"#,
+        r#"
+struct Foo;
+impl Foo { const $1: $2 = $0; }
+"#,
+    );
+    check_edit(
+        "type (Associated type)",
+        r#"
+struct Foo;
+impl Foo { $0 }
+"#,
+        r#"
+struct Foo;
+impl Foo { type $1 = $0; }
+"#,
+    );
+}
+
 #[test]
 fn in_trait_assoc_item_list() {
     check(
@@ -204,6 +255,7 @@ fn in_trait_assoc_item_list() {
         expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw fn
@@ -221,6 +273,7 @@ fn in_trait_assoc_fn_missing_body() {
         expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw fn
@@ -238,6 +291,7 @@ fn in_trait_assoc_const_missing_body() {
         expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw fn
@@ -255,6 +309,7 @@ fn in_trait_assoc_type_aliases_missing_ty() {
         expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw fn
@@ -356,6 +411,7 @@ fn after_unit_struct() {
         expect![[r#"
             ma makro!(…)           macro_rules! makro
             md module
+            kw async
             kw const
             kw crate::
             kw enum
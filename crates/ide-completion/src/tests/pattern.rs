@@ -35,6 +35,38 @@ fn quux() {
     );
 }
 
+#[test]
+fn let_binding_shadows_local() {
+    check_empty(
+        r#"
+struct Point { x: u32, y: u32 }
+fn quux(x: u32) {
+    let P$0
+}
+"#,
+        expect![[r#"
+            st Point
+            bn Point {…} Point { x$1, y$2 }$0 SHADOWING
+            kw mut
+            kw ref
+        "#]],
+    );
+    check_empty(
+        r#"
+struct Point { x: u32, y: u32 }
+fn quux(z: u32) {
+    let P$0
+}
+"#,
+        expect![[r#"
+            st Point
+            bn Point {…} Point { x$1, y$2 }$0
+            kw mut
+            kw ref
+        "#]],
+    );
+}
+
 #[test]
 fn ident_ref_pat() {
     check_empty(
@@ -137,6 +169,44 @@ fn foo() {
     );
 }
 
+#[test]
+fn const_in_match_arm() {
+    check_empty(
+        r#"
+const MAX: i32 = 10;
+fn foo(x: i32) {
+    match x {
+        M$0
+    }
+}
+"#,
+        expect![[r#"
+            ct MAX
+            kw mut
+            kw ref
+        "#]],
+    );
+    check_edit(
+        "MAX",
+        r#"
+const MAX: i32 = 10;
+fn foo(x: i32) {
+    match x {
+        M$0
+    }
+}
+"#,
+        r#"
+const MAX: i32 = 10;
+fn foo(x: i32) {
+    match x {
+        MAX
+    }
+}
+"#,
+    );
+}
+
 #[test]
 fn irrefutable() {
     check(
@@ -225,6 +295,44 @@ fn foo() {
     );
 }
 
+#[test]
+fn slice_pattern_element() {
+    check_empty(
+        r#"
+enum E { X, Y }
+
+fn foo(es: &[E]) {
+    match es {
+        [E::X, $0] => (),
+        _ => (),
+    }
+}
+"#,
+        expect![[r#"
+            en E
+            bn E::X  E::X$0
+            bn E::Y  E::Y$0
+            kw ..
+            kw mut
+            kw ref
+        "#]],
+    );
+    check_empty(
+        r#"
+fn foo(xs: &[u32]) {
+    match xs {
+        [.., $0] => (),
+        _ => (),
+    }
+}
+"#,
+        expect![[r#"
+            kw mut
+            kw ref
+        "#]],
+    );
+}
+
 #[test]
 fn in_simple_macro_call() {
     check_empty(
@@ -246,6 +354,59 @@ fn foo() {
     );
 }
 
+#[test]
+fn in_matches_macro() {
+    check_edit(
+        "Some()",
+        r#"
+//- minicore: option, matches
+fn foo(opt: Option<i32>) {
+    matches!(opt, $0);
+}
+"#,
+        r#"
+fn foo(opt: Option<i32>) {
+    matches!(opt, Some($1)$0);
+}
+"#,
+    );
+    check_edit(
+        "None",
+        r#"
+//- minicore: option, matches
+fn foo(opt: Option<i32>) {
+    matches!(opt, $0);
+}
+"#,
+        r#"
+fn foo(opt: Option<i32>) {
+    matches!(opt, None$0);
+}
+"#,
+    );
+    // variants of the scrutinee's type are offered even if they are not in scope
+    check_edit(
+        "m::E::B",
+        r#"
+//- minicore: matches
+mod m {
+    pub enum E { A, B }
+}
+fn foo(e: m::E) {
+    matches!(e, $0);
+}
+"#,
+        r#"
+mod m {
+    pub enum E { A, B }
+}
+fn foo(e: m::E) {
+    matches!(e, m::E::B$0);
+}
+"#,
+    );
+}
+
 #[test]
 fn omits_private_fields_pat() {
     check_empty(
@@ -671,10 +832,11 @@ impl Ty {
             st Ty
             bn &mut self
             bn &self
-            bn Self(…)   Self($1): Self$0
-            bn Ty(…)     Ty($1): Ty$0
+            bn Self(…)         Self($1): Self$0
+            bn Ty(…)           Ty($1): Ty$0
             bn mut self
             bn self
+            bn self: Box<Self>
             kw mut
             kw ref
         "#]],
@@ -692,10 +854,11 @@ impl Ty {
             st Ty
             bn &mut self
             bn &self
-            bn Self(…)   Self($1): Self$0
-            bn Ty(…)     Ty($1): Ty$0
+            bn Self(…)         Self($1): Self$0
+            bn Ty(…)           Ty($1): Ty$0
             bn mut self
             bn self
+            bn self: Box<Self>
             kw mut
             kw ref
         "#]],
@@ -713,10 +876,11 @@ impl Ty {
             st Ty
             bn &mut self
             bn &self
-            bn Self(…)   Self($1): Self$0
-            bn Ty(…)     Ty($1): Ty$0
+            bn Self(…)         Self($1): Self$0
+            bn Ty(…)           Ty($1): Ty$0
             bn mut self
             bn self
+            bn self: Box<Self>
             kw mut
             kw ref
         "#]],
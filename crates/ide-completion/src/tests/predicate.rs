@@ -42,6 +42,7 @@ struct Foo<'lt, T, const C: usize> where T: $0 {}
             ma makro!(…) macro_rules! makro
             md module
             tt Trait
+            kw ?Sized
             kw crate::
             kw self::
         "#]],
@@ -76,6 +77,7 @@ struct Foo<'lt, T, const C: usize> where for<'a> T: $0 {}
             ma makro!(…) macro_rules! makro
             md module
             tt Trait
+            kw ?Sized
             kw crate::
             kw self::
         "#]],
@@ -143,6 +145,7 @@ pub trait Trait {}
 "#,
         expect![[r#"
             md std
+            kw ?Sized
             kw crate::
             kw self::
         "#]],
@@ -164,8 +167,75 @@ pub trait Trait {}
         expect![[r#"
             md std
             tt Trait
+            kw ?Sized
             kw crate::
             kw self::
         "#]],
     );
 }
+
+#[test]
+fn relaxed_sized_bound() {
+    check(
+        r#"
+fn f<T: $0>() {}
+"#,
+        expect![[r#"
+            ma makro!(…) macro_rules! makro
+            md module
+            tt Trait
+            kw ?Sized
+            kw crate::
+            kw self::
+        "#]],
+    );
+    check(
+        r#"
+trait Foo: $0 {}
+"#,
+        expect![[r#"
+            ma makro!(…) macro_rules! makro
+            md module
+            tt Foo
+            tt Trait
+            kw crate::
+            kw self::
+        "#]],
+    );
+    check(
+        r#"
+trait Foo {
+    type Assoc: $0;
+}
+"#,
+        expect![[r#"
+            ma makro!(…) macro_rules! makro
+            md module
+            tt Foo
+            tt Trait
+            kw ?Sized
+            kw crate::
+            kw self::
+        "#]],
+    );
+}
+
+#[test]
+fn self_bounds_in_trait_method_where_clause() {
+    let actual = completion_list(
+        r#"
+trait Foo {
+    fn foo(self) where $0;
+}
+"#,
+    );
+    assert!(actual.contains("kw Self: Sized"), "{actual}");
+    assert!(actual.contains("kw Self: 'static"), "{actual}");
+
+    let actual = completion_list(
+        r#"
+fn foo<T>() where $0 {}
+"#,
+    );
+    assert!(!actual.contains("Self: Sized"), "{actual}");
+}
@@ -1,6 +1,9 @@
 use expect_test::{expect, Expect};
 
-use crate::tests::completion_list;
+use crate::{
+    item::CompletionRelevanceTypeMatch,
+    tests::{completion_list, get_all_items, TEST_CONFIG},
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(ra_fixture);
@@ -162,6 +165,28 @@ fn foo(f: Struct) {
     );
 }
 
+#[test]
+fn functional_update_base_of_struct_type() {
+    let items = get_all_items(
+        TEST_CONFIG,
+        r#"
+struct Foo { x: u32, y: u32 }
+struct Bar;
+
+fn main() {
+    let base = Foo { x: 0, y: 0 };
+    let other = Bar;
+    let foo = Foo { x: 1, ..$0 };
+}
+"#,
+        None,
+    );
+    let type_match_of =
+        |label: &str| items.iter().find(|it| it.label == label).unwrap().relevance.type_match;
+    assert_eq!(type_match_of("base"), Some(CompletionRelevanceTypeMatch::Exact));
+    assert_eq!(type_match_of("other"), None);
+}
+
 #[test]
 fn in_functional_update() {
     cov_mark::check!(functional_update);
@@ -182,6 +207,7 @@ fn main() {
 "#,
         expect![[r#"
             fd ..Default::default()
+            fn Foo::default()
             fn main()               fn()
             lc foo                  Foo
             lc thing                i32
@@ -234,6 +260,7 @@ fn main() {
 "#,
         expect![[r#"
             fd ..Default::default()
+            fd ..foo
             fd foo1                 u32
             fd foo2                 u32
         "#]],
@@ -259,6 +286,7 @@ fn main() {
 "#,
         expect![[r#"
             fd ..Default::default()
+            fd ..foo
             sn ..
         "#]],
     );
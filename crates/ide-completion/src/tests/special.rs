@@ -261,6 +261,26 @@ fn foo() { let _ = Trait::$0 }
     );
 }
 
+#[test]
+fn completes_trait_items_in_ufcs_path() {
+    check_no_kw(
+        r#"
+trait Iterator {
+    type Item;
+    fn next(&mut self);
+    fn map<F>(self, f: F) {}
+}
+
+fn foo() { let _ = Iterator::$0 }
+"#,
+        expect![[r#"
+                ta Item (as Iterator)    type Item
+                me map(…) (as Iterator)  fn(self, F)
+                me next(…) (as Iterator) fn(&mut self)
+            "#]],
+    );
+}
+
 #[test]
 fn completes_trait_associated_method_2() {
     check_no_kw(
@@ -1010,6 +1030,7 @@ fn here_we_go() {
             fn here_we_go()    fn()
             st Foo (alias Bar) Foo
             bt u32             u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -1059,6 +1080,7 @@ fn here_we_go() {
             fn here_we_go()           fn()
             st Foo (alias Bar, Qux, Baz) Foo
             bt u32                    u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -1179,6 +1201,7 @@ fn bar() { qu$0 }
             fn bar()             fn()
             fn foo() (alias qux) fn()
             bt u32               u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -1269,6 +1292,8 @@ fn here_we_go() {
             md foo
             st Bar (alias Qux) (use foo::Bar) Bar
             bt u32                    u32
+            kw async
+            kw async move
             kw crate::
             kw false
             kw for
@@ -1282,6 +1307,7 @@ fn here_we_go() {
             kw unsafe
             kw while
             kw while let
+            sn const
         "#]],
     );
 }
@@ -1434,6 +1460,7 @@ fn foo() {
         expect![[r#"
             fn foo()       fn()
             bt u32         u32
+            kw async
             kw const
             kw crate::
             kw enum
@@ -1,7 +1,7 @@
 //! Completion tests for type position.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_empty, completion_list, BASE_ITEMS_FIXTURE};
+use crate::tests::{check_edit, check_empty, completion_list, BASE_ITEMS_FIXTURE};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(&format!("{BASE_ITEMS_FIXTURE}\n{ra_fixture}"));
@@ -81,11 +81,39 @@ fn x<'lt, T, const C: usize>() -> $0
             un Union     Union
             bt u32       u32
             kw crate::
+            kw dyn
+            kw impl
             kw self::
         "#]],
     );
 }
 
+#[test]
+fn fn_return_type_keyword_snippets() {
+    check_edit(
+        "impl",
+        r#"
+trait Trait {}
+fn foo() -> $0
+"#,
+        r#"
+trait Trait {}
+fn foo() -> impl $0
+"#,
+    );
+    check_edit(
+        "dyn",
+        r#"
+trait Trait {}
+fn foo() -> $0
+"#,
+        r#"
+trait Trait {}
+fn foo() -> dyn $0
+"#,
+    );
+}
+
 #[test]
 fn fn_return_type_no_local_items() {
     check(
@@ -111,6 +139,8 @@ fn foo() -> B$0 {
             bt u32       u32
             it ()
             kw crate::
+            kw dyn
+            kw impl
             kw self::
         "#]],
     )
@@ -215,6 +245,8 @@ fn f2(x: u64) -> $0 {
             bt u32       u32
             it u64
             kw crate::
+            kw dyn
+            kw impl
             kw self::
         "#]],
     );
@@ -537,6 +569,22 @@ fn f(t: impl MyTrait<u8, u8, I$0
     );
 }
 
+#[test]
+fn completes_associated_type_in_return_position_impl_trait() {
+    check(
+        r#"
+trait Iterator {
+    type Item;
+}
+
+fn f() -> impl Iterator<$0> {}
+"#,
+        expect![[r#"
+            ta Item =  (as Iterator) type Item
+        "#]],
+    );
+}
+
 #[test]
 fn completes_type_parameter_or_associated_type_with_default_value() {
     check(
@@ -753,9 +801,6 @@ fn completes_const_and_type_generics_separately() {
             kw self::
         "#]],
     );
-    // FIXME: This should probably also suggest completions for types, at least those that have
-    // associated constants usable in this position. For example, a user could be typing
-    // `foo::<_, { usize::MAX }>()`, but we currently don't suggest `usize` in constant position.
     check(
         r#"
     struct Foo;
@@ -771,6 +816,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -814,6 +860,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -858,6 +905,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -876,6 +924,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -895,6 +944,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -913,6 +963,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -929,6 +980,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -947,6 +999,7 @@ fn completes_const_and_type_generics_separately() {
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
 
@@ -961,6 +1014,7 @@ fn foo<'a>() { S::<F$0, _>; }
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
     // Explicit lifetime params
@@ -974,6 +1028,7 @@ fn foo<'a>() { S::<'static, 'static, F$0, _>; }
             ma makro!(…) macro_rules! makro
             kw crate::
             kw self::
+            sn {…}
         "#]],
     );
     check(
@@ -989,3 +1044,130 @@ fn foo<'a>() { S::<'static, F$0, _, _>; }
         "#]],
     );
 }
+
+#[test]
+fn completes_types_in_turbofish_type_arg() {
+    check(
+        r#"
+struct Vec<T>(T);
+fn foo() {
+    let local = 0;
+    Vec::<$0>;
+}
+"#,
+        expect![[r#"
+            en Enum      Enum
+            ma makro!(…) macro_rules! makro
+            md module
+            st Record    Record
+            st Tuple     Tuple
+            st Unit      Unit
+            st Vec<…>    Vec<{unknown}>
+            tt Trait
+            un Union     Union
+            bt u32       u32
+            kw crate::
+            kw self::
+        "#]],
+    );
+
+    let builtins = crate::tests::do_completion(
+        r#"
+struct Vec<T>(T);
+fn foo() {
+    Vec::<$0>;
+}
+"#,
+        crate::CompletionItemKind::BuiltinType,
+    );
+    assert!(builtins.iter().any(|it| it.label == "u8"));
+}
+
+#[test]
+fn collect_turbofish_offers_viable_collections() {
+    let structs = crate::tests::do_completion(
+        r#"
+//- minicore: from_iterator
+use core::iter::FromIterator;
+
+struct String;
+impl FromIterator<char> for String {
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self { String }
+}
+struct Vec<T>(T);
+impl<T> FromIterator<T> for Vec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self { loop {} }
+}
+struct Bytes;
+impl FromIterator<u8> for Bytes {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self { Bytes }
+}
+
+struct Chars;
+impl Iterator for Chars {
+    type Item = char;
+    fn next(&mut self) -> Option<char> { None }
+}
+
+fn foo(chars: Chars) {
+    chars.collect::<$0>();
+}
+"#,
+        crate::CompletionItemKind::SymbolKind(ide_db::SymbolKind::Struct),
+    );
+    let targets: Vec<_> = structs
+        .iter()
+        .filter(|it| {
+            it.relevance.type_match == Some(crate::item::CompletionRelevanceTypeMatch::Exact)
+        })
+        .map(|it| it.label.as_str())
+        .collect();
+    assert_eq!(targets, ["String", "Vec<_>"]);
+    assert!(structs.iter().any(|it| it.label == "Bytes"));
+}
+
+#[test]
+fn assoc_const_in_const_generic_arg() {
+    let fixture = r#"
+struct Foo<const N: usize>;
+struct Bar;
+impl Bar {
+    const N: usize = 3;
+    const NAME: &'static str = "bar";
+}
+
+fn f(_: Foo<$0>) {}
+"#;
+    let actual = completion_list(fixture);
+    assert!(actual.contains("ct Bar::N"), "{actual}");
+    assert!(!actual.contains("Bar::NAME"), "{actual}");
+    check_edit(
+        "Bar::N",
+        fixture,
+        r#"
+struct Foo<const N: usize>;
+struct Bar;
+impl Bar {
+    const N: usize = 3;
+    const NAME: &'static str = "bar";
+}
+
+fn f(_: Foo<{ Bar::N }>) {}
+"#,
+    );
+}
+
+#[test]
+fn const_arg_block_snippet() {
+    check_edit(
+        "{…}",
+        r#"
+struct Foo<const N: usize>;
+fn f(_: Foo<$0>) {}
+"#,
+        r#"
+struct Foo<const N: usize>;
+fn f(_: Foo<{ $0 }>) {}
+"#,
+    );
+}
@@ -101,6 +101,26 @@ use foo::{$0}
     );
 }
 
+#[test]
+fn use_tree_group_skips_members() {
+    cov_mark::check!(skip_use_tree_group_members);
+    check(
+        r#"
+mod foo {
+    pub mod a {}
+    pub mod b {}
+    pub mod c {}
+}
+use foo::{a, $0};
+"#,
+        expect![[r#"
+            md b
+            md c
+            kw self
+        "#]],
+    );
+}
+
 #[test]
 fn deeply_nested_use_tree() {
     check(
@@ -790,12 +790,14 @@ impl From<ModuleDef> for Definition {
     }
 }
 
-impl From<DocLinkDef> for Definition {
-    fn from(def: DocLinkDef) -> Self {
+impl TryFrom<DocLinkDef> for Definition {
+    type Error = ();
+    fn try_from(def: DocLinkDef) -> Result<Self, Self::Error> {
         match def {
-            DocLinkDef::ModuleDef(it) => it.into(),
-            DocLinkDef::Field(it) => it.into(),
-            DocLinkDef::SelfType(it) => it.into(),
+            DocLinkDef::ModuleDef(it) => Ok(it.into()),
+            DocLinkDef::Field(it) => Ok(it.into()),
+            DocLinkDef::SelfType(it) => Ok(it.into()),
+            DocLinkDef::CfgInactive(_) => Err(()),
         }
     }
 }
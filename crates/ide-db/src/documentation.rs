@@ -168,7 +168,7 @@ macro_rules! impl_has_docs {
                 link: &str,
                 ns: Option<hir::Namespace>
             ) -> Option<hir::DocLinkDef> {
-                resolve_doc_path_on(db, self, link, ns)
+                resolve_doc_path_on(db, self, link, ns, Default::default())
             }
         }
     )*};
@@ -246,7 +246,7 @@ impl HasDocs for hir::ExternCrateDecl {
         link: &str,
         ns: Option<hir::Namespace>,
     ) -> Option<hir::DocLinkDef> {
-        resolve_doc_path_on(db, self, link, ns)
+        resolve_doc_path_on(db, self, link, ns, Default::default())
     }
 }
 
@@ -78,6 +78,10 @@ impl FamousDefs<'_, '_> {
         self.find_trait("core:iter:traits:collect:IntoIterator")
     }
 
+    pub fn core_iter_FromIterator(&self) -> Option<Trait> {
+        self.find_trait("core:iter:traits:collect:FromIterator")
+    }
+
     pub fn core_iter(&self) -> Option<Module> {
         self.find_module("core:iter")
     }
@@ -94,6 +98,18 @@ impl FamousDefs<'_, '_> {
         self.find_trait("core:convert:AsRef")
     }
 
+    pub fn core_convert_AsMut(&self) -> Option<Trait> {
+        self.find_trait("core:convert:AsMut")
+    }
+
+    pub fn core_borrow_Borrow(&self) -> Option<Trait> {
+        self.find_trait("core:borrow:Borrow")
+    }
+
+    pub fn core_borrow_BorrowMut(&self) -> Option<Trait> {
+        self.find_trait("core:borrow:BorrowMut")
+    }
+
     pub fn core_ops_ControlFlow(&self) -> Option<Enum> {
         self.find_enum("core:ops:ControlFlow")
     }
@@ -106,6 +122,10 @@ impl FamousDefs<'_, '_> {
         self.find_trait("core:marker:Copy")
     }
 
+    pub fn core_marker_Sized(&self) -> Option<Trait> {
+        self.find_trait("core:marker:Sized")
+    }
+
     pub fn core_macros_builtin_derive(&self) -> Option<Macro> {
         self.find_macro("core:macros:builtin:derive")
     }
@@ -31,7 +31,7 @@ use syntax::{
 
 use crate::{
     doc_links::intra_doc_links::{parse_intra_doc_link, strip_prefixes_suffixes},
-    FilePosition, Semantics,
+    FilePosition, NavigationTarget, Semantics, TryToNav,
 };
 
 /// Web and local links to an item's documentation.
@@ -134,11 +134,19 @@ pub(crate) fn remove_links(markdown: &str) -> String {
 // |===
 pub(crate) fn external_docs(
     db: &RootDatabase,
-    FilePosition { file_id, offset }: FilePosition,
+    position: FilePosition,
     target_dir: Option<&OsStr>,
     sysroot: Option<&OsStr>,
 ) -> Option<DocumentationLinks> {
     let sema = &Semantics::new(db);
+    let definition = definition_at(sema, position)?;
+    Some(get_doc_links(db, definition, target_dir, sysroot))
+}
+
+fn definition_at(
+    sema: &Semantics<'_, RootDatabase>,
+    FilePosition { file_id, offset }: FilePosition,
+) -> Option<Definition> {
     let file = sema.parse(file_id).syntax().clone();
     let token = pick_best_token(file.token_at_offset(offset), |kind| match kind {
         IDENT | INT_NUMBER | T![self] => 3,
@@ -168,7 +176,7 @@ pub(crate) fn external_docs(
         }
     };
 
-    Some(get_doc_links(db, definition, target_dir, sysroot))
+    Some(definition)
 }
 
 /// Extracts all links from a given markdown text returning the definition text range, link-text
@@ -196,6 +204,28 @@ pub(crate) fn extract_definitions_from_docs(
     .collect()
 }
 
+/// Extracts all intra-doc links from the documentation of the item at `position` together with the
+/// navigation targets of the items they resolve to, unresolved links map to `None`.
+pub(crate) fn doc_links(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<Vec<(String, Option<Vec<NavigationTarget>>)>> {
+    let sema = &Semantics::new(db);
+    let def = definition_at(sema, position)?;
+    let docs = def.docs(db, None)?;
+    let links = extract_definitions_from_docs(&docs)
+        .into_iter()
+        // Plain URLs are not intra-doc links, so there is nothing to resolve.
+        .filter(|(_, link, _)| !link.contains("://"))
+        .map(|(_, link, ns)| {
+            let targets = resolve_doc_path_for_def(db, def, &link, ns)
+                .map(|it| it.try_to_nav(db).map(|it| it.collect()).unwrap_or_default());
+            (link, targets)
+        })
+        .collect();
+    Some(links)
+}
+
 pub(crate) fn resolve_doc_path_for_def(
     db: &dyn HirDatabase,
     def: Definition,
@@ -225,7 +255,7 @@ pub(crate) fn resolve_doc_path_for_def(
         | Definition::Label(_)
         | Definition::DeriveHelper(_) => None,
     }
-    .map(Definition::from)
+    .and_then(|it| Definition::try_from(it).ok())
 }
 
 pub(crate) fn doc_attributes(
@@ -1,7 +1,7 @@
 use std::{ffi::OsStr, iter};
 
 use expect_test::{expect, Expect};
-use hir::Semantics;
+use hir::{AsAssocItem, HasSource, Semantics};
 use ide_db::{
     base_db::{FilePosition, FileRange},
     defs::Definition,
@@ -78,6 +78,55 @@ fn check_doc_links(ra_fixture: &str) {
     assert_eq!(expected, actual);
 }
 
+fn check_doc_links_unresolved(ra_fixture: &str) {
+    let (analysis, position) = fixture::position(ra_fixture);
+    let sema = &Semantics::new(&*analysis.db);
+    let (cursor_def, docs) = def_under_cursor(sema, &position);
+    for (_, link, ns) in extract_definitions_from_docs(&docs) {
+        let def = resolve_doc_path_for_def(sema.db, cursor_def, &link, ns);
+        assert!(def.is_none(), "{link} unexpectedly resolved to {def:?}");
+    }
+}
+
+/// Like [`check_doc_links`], but resolves the links of the ADT under the cursor with `resolve`,
+/// which returns the item a link points to together with the label it is annotated with.
+fn check_doc_links_with(
+    ra_fixture: &str,
+    resolve: impl Fn(&RootDatabase, hir::Adt, &str) -> Option<(hir::DocLinkDef, String)>,
+) {
+    let key_fn = |&(FileRange { file_id, range }, _): &_| (file_id, range.start());
+
+    let (analysis, position, mut expected) = fixture::annotations(ra_fixture);
+    expected.sort_by_key(key_fn);
+    let sema = &Semantics::new(&*analysis.db);
+    let (Definition::Adt(adt), docs) = def_under_cursor(sema, &position) else {
+        panic!("expected an adt under the cursor");
+    };
+    let actual: Vec<_> = extract_definitions_from_docs(&docs)
+        .into_iter()
+        .filter_map(|(_, link, _)| {
+            let (def, label) = resolve(sema.db, adt, &link)?;
+            Some((doc_link_def_range(sema.db, def), label))
+        })
+        .sorted_by_key(key_fn)
+        .collect();
+    assert_eq!(expected, actual);
+}
+
+fn doc_link_def_range(db: &RootDatabase, def: hir::DocLinkDef) -> FileRange {
+    match def {
+        hir::DocLinkDef::CfgInactive(it) => {
+            let src = it.source(db).unwrap();
+            let name = src.value.syntax().children().find_map(ast::Name::cast).unwrap();
+            FileRange { file_id: src.file_id.original_file(db), range: name.syntax().text_range() }
+        }
+        def => {
+            let nav = Definition::try_from(def).unwrap().try_to_nav(db).unwrap().call_site();
+            FileRange { file_id: nav.file_id, range: nav.focus_or_full_range() }
+        }
+    }
+}
+
 fn def_under_cursor(
     sema: &Semantics<'_, RootDatabase>,
     position: &FilePosition,
@@ -522,6 +571,354 @@ fn function();
     )
 }
 
+#[test]
+fn doc_links_trait_assoc_const() {
+    check_doc_links(
+        r#"
+trait MyTrait {
+    const MAX: u32;
+       // ^^^ MyTrait::MAX
+       // ^^^ MyTrait::MAX
+}
+
+/// [`MyTrait::MAX`]
+/// [value@MyTrait::MAX]
+struct Foo$0;
+"#,
+    );
+    check_doc_links_unresolved(
+        r#"
+trait MyTrait {
+    const MAX: u32;
+}
+
+/// [type@MyTrait::MAX]
+struct Foo$0;
+"#,
+    );
+}
+
+#[test]
+fn doc_links_lang_item_traits() {
+    check_doc_links(
+        r#"
+//- /main.rs crate:main deps:core
+/// [`FnMut`]
+/// [`FnMut::call_mut`]
+struct S$0;
+//- /core.rs crate:core
+pub mod ops {
+    #[lang = "fn_mut"]
+    pub trait FnMut<Args> {
+           // ^^^^^ FnMut
+        fn call_mut(&mut self, args: Args);
+        // ^^^^^^^^ FnMut::call_mut
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_renamed_dependency() {
+    check_doc_links(
+        r#"
+//- /main.rs crate:main deps:foo=bar
+/// [`foo::Item`]
+pub struct S$0;
+//- /bar.rs crate:bar
+pub struct Item;
+        // ^^^^ foo::Item
+"#,
+    );
+}
+
+#[test]
+fn doc_links_path_attr_module() {
+    check_doc_links(
+        r#"
+//- /main.rs crate:main
+#[path = "other.rs"]
+mod weird_mod;
+
+/// [weird_mod::Item]
+/// [`weird_mod::nested::Inner`]
+pub struct S$0;
+//- /other.rs
+pub struct Item;
+        // ^^^^ weird_mod::Item
+pub mod nested {
+    pub struct Inner;
+            // ^^^^^ weird_mod::nested::Inner
+}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_glob_and_renamed_reexport() {
+    check_doc_links(
+        r#"
+mod inner {
+    pub struct Special;
+            // ^^^^^^^ Renamed
+            // ^^^^^^^ Special
+}
+pub use inner::*;
+pub use inner::Special as Renamed;
+
+/// [`Renamed`]
+/// [`Special`]
+pub struct Foo$0;
+"#,
+    );
+}
+
+#[test]
+fn doc_links_primitive_assoc_fn() {
+    check_doc_links(
+        r#"
+#![rustc_coherence_is_core]
+impl i32 {
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<i32, ()> { loop {} }
+        // ^^^^^^^^^^^^^^ i32::from_str_radix
+}
+enum Result<T, E> { Ok(T), Err(E) }
+
+/// [`i32::from_str_radix`]
+struct Foo$0;
+"#,
+    );
+    check_doc_links_unresolved(
+        r#"
+#![rustc_coherence_is_core]
+impl i32 {
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<i32, ()> { loop {} }
+}
+enum Result<T, E> { Ok(T), Err(E) }
+
+/// [`integer`]
+/// [`integer::from_str_radix`]
+struct Foo$0;
+"#,
+    );
+}
+
+#[test]
+fn doc_links_reports_unresolved() {
+    let (analysis, position) = fixture::position(
+        r#"
+pub struct Bar;
+
+/// [`Bar`]
+/// [`Missing`]
+pub struct Foo$0;
+"#,
+    );
+    let links = analysis.doc_links(position).unwrap();
+    let [(bar, Some(targets)), (missing, None)] = &links[..] else {
+        panic!("unexpected doc links {links:?}");
+    };
+    assert_eq!(bar, "Bar");
+    assert_eq!(targets.iter().map(|it| it.name.to_string()).collect::<Vec<_>>(), ["Bar"]);
+    assert_eq!(missing, "Missing");
+}
+
+#[test]
+fn doc_link_to_deprecated_item() {
+    check_doc_links_with(
+        r#"
+#[deprecated]
+fn old() {}
+// ^^^ deprecated
+fn new() {}
+// ^^^ not deprecated
+
+/// [`old`]
+/// [`new`]
+struct Foo$0;
+"#,
+        |db, adt, link| {
+            let resolve = || hir::resolve_doc_path_on(db, adt, link, None, Default::default());
+            let is_deprecated = hir::doc_link_def_is_deprecated(db, resolve()?);
+            let label = if is_deprecated { "deprecated" } else { "not deprecated" };
+            Some((resolve()?, label.to_owned()))
+        },
+    );
+}
+
+#[test]
+fn doc_link_resolved_crate() {
+    check_doc_links_with(
+        r#"
+//- /main.rs crate:main deps:dep
+struct Local;
+    // ^^^^^ main
+
+/// [`dep::Item`]
+/// [`Local`]
+struct Foo$0;
+//- /dep.rs crate:dep
+pub struct Item;
+        // ^^^^ dep
+"#,
+        |db, adt, link| {
+            let (def, krate) = hir::resolve_doc_path_with_crate_on(db, adt, link, None)?;
+            Some((def, krate.display_name(db)?.to_string()))
+        },
+    );
+}
+
+#[test]
+fn doc_link_prefix_resolution() {
+    // Links are annotated with their unresolved remainder.
+    check_doc_links_with(
+        r#"
+mod foo {
+ // ^^^ "ba"
+    pub struct Bar;
+            // ^^^ ""
+}
+
+/// [`foo::ba`]
+/// [`foo::Bar`]
+/// [`baz::ba`]
+struct Foo$0;
+"#,
+        |db, adt, link| {
+            let (def, remaining) = hir::resolve_doc_path_prefix(db, adt, link)?;
+            Some((def, format!("{remaining:?}")))
+        },
+    );
+}
+
+#[test]
+fn doc_links_synthetic_names() {
+    check_doc_links_unresolved(
+        r#"
+/// [`{{closure}}`]
+/// [`Foo::{{closure}}`]
+/// [Foo]()
+/// [Foo](< >)
+struct Foo$0;
+"#,
+    );
+}
+
+#[test]
+fn doc_links_unicode_identifiers() {
+    check_doc_links(
+        r#"
+struct Café;
+    // ^^^^^ Café
+impl Café {
+    fn método(&self) {}
+    // ^^^^^^^ Café::método
+}
+
+/// [`Café`]
+/// [`Café::método`]
+struct Foo$0;
+"#,
+    );
+}
+
+#[test]
+fn doc_links_group_syntax() {
+    check_doc_links_unresolved(
+        r#"
+mod foo {
+    pub struct bar;
+}
+
+/// [`foo::{bar}`]
+/// [`foo::{bar, baz}`]
+/// [`{foo}`]
+struct Foo$0;
+"#,
+    );
+}
+
+#[test]
+fn doc_links_legacy_slash_separators() {
+    let fixture = r#"
+mod foo {
+    pub struct Bar;
+            // ^^^ foo/Bar
+}
+
+/// [foo/Bar]
+struct Foo$0;
+"#;
+    check_doc_links_unresolved(fixture);
+    check_doc_links_with(fixture, |db, adt, link| {
+        let options =
+            hir::DocPathResolveOptions { legacy_slash_separators: true, ..Default::default() };
+        Some((hir::resolve_doc_path_on(db, adt, link, None, options)?, link.to_owned()))
+    });
+}
+
+#[test]
+fn doc_links_cfg_inactive_items() {
+    let fixture = r#"
+mod foo {
+    #[cfg(feature = "x")]
+    pub struct Gated;
+            // ^^^^^ foo::Gated
+}
+
+#[cfg(feature = "x")]
+fn local_gated() {}
+// ^^^^^^^^^^^ local_gated
+
+/// [`foo::Gated`]
+/// [`local_gated`]
+struct Foo$0;
+"#;
+    check_doc_links_unresolved(fixture);
+    check_doc_links_with(fixture, |db, adt, link| {
+        let options =
+            hir::DocPathResolveOptions { include_cfg_inactive: true, ..Default::default() };
+        Some((hir::resolve_doc_path_on(db, adt, link, None, options)?, link.to_owned()))
+    });
+}
+
+#[test]
+fn doc_links_restricted_visibility() {
+    let check = |ra_fixture: &str| {
+        check_doc_links_with(ra_fixture, |db, adt, link| {
+            let def = hir::resolve_doc_path_on(db, adt, link, None, Default::default())?;
+            let is_visible = hir::resolve_visible_doc_path_on(db, adt, link, None).is_some();
+            Some((def, if is_visible { "visible" } else { "not visible" }.to_owned()))
+        })
+    };
+
+    check(
+        r#"
+mod foo {
+    pub mod bar {
+        pub(in crate::foo) struct Restricted;
+                               // ^^^^^^^^^^ visible
+    }
+    /// [bar::Restricted]
+    pub struct Inside$0;
+}
+"#,
+    );
+    check(
+        r#"
+mod foo {
+    pub mod bar {
+        pub(in crate::foo) struct Restricted;
+                               // ^^^^^^^^^^ not visible
+    }
+}
+/// [foo::bar::Restricted]
+pub struct Outside$0;
+"#,
+    );
+}
+
 #[test]
 fn doc_links_field() {
     check_doc_links(
@@ -554,6 +951,103 @@ enum E {
     );
 }
 
+#[test]
+fn doc_links_self_assoc_type_method() {
+    check_doc_links(
+        r#"
+trait Iterator {
+    type Item;
+    fn next(&mut self);
+}
+
+struct Bytes;
+impl Bytes {
+    pub fn len(&self) -> usize { 0 }
+        // ^^^ Self::Item::len
+}
+
+struct S;
+impl Iterator for S {
+    type Item = Bytes;
+    /// [`Self::Item::len`]
+    fn next$0(&mut self) {}
+}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_inherent_assoc_const_and_type() {
+    check_doc_links(
+        r#"
+struct MyType;
+impl MyType {
+    const CONST: u32 = 0;
+        //^^^^^ MyType::CONST
+    type Assoc = u32;
+       //^^^^^ MyType::Assoc
+}
+
+/// [`MyType::CONST`]
+/// [`MyType::Assoc`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_self_in_method_docs() {
+    check_doc_links(
+        r#"
+struct Foo;
+impl Foo {
+    const OTHER: u32 = 0;
+        //^^^^^ Self::OTHER
+    fn bar(&self) {}
+     //^^^ Self::bar
+    /// [`Self::bar`]
+    /// [`Self::OTHER`]
+    fn baz$0(&self) {}
+}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_self_assoc_items_on_trait() {
+    check_doc_links(
+        r#"
+/// [`Self::MAX`]
+/// [`Self::Item`]
+/// [`Self::f`]
+trait Foo$0 {
+    const MAX: u32;
+        //^^^ Self::MAX
+    type Item;
+       //^^^^ Self::Item
+    fn f();
+     //^ Self::f
+}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_self_field_in_trait_default_method() {
+    check_doc_links_unresolved(
+        r#"
+trait Trait {
+    /// [`Self::field`]
+    fn method$0(&self) {}
+}
+struct S {
+    field: u32,
+}
+impl Trait for S {}
+"#,
+    );
+}
+
 #[test]
 fn doc_links_field_via_self() {
     check_doc_links(
@@ -578,6 +1072,401 @@ struct S$0(i32);
     );
 }
 
+#[test]
+fn doc_links_tuple_field_multi_segment() {
+    check_doc_links(
+        r#"
+mod foo {
+    pub struct Point(pub i32, pub i32);
+                   //^^^^^^^ crate::foo::Point::0
+                            //^^^^^^^ foo::Point::1
+}
+/// [`crate::foo::Point::0`]
+/// [`foo::Point::1`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_generic_inherent_impl_assoc_fn() {
+    check_doc_links(
+        r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn with_capacity(capacity: usize) -> Self { loop {} }
+     //^^^^^^^^^^^^^ Vec::with_capacity
+}
+
+/// [`Vec::with_capacity`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_generic_type_alias_method() {
+    check_doc_links(
+        r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn push(&mut self, value: T) {}
+     //^^^^ IntVec::push
+}
+impl Vec<u8> {
+    fn bytes_only(&self) {}
+}
+type IntVec = Vec<i32>;
+
+/// [`IntVec::push`]
+fn main$0() {}
+"#,
+    );
+    check_doc_links_unresolved(
+        r#"
+struct Vec<T>(T);
+impl Vec<u8> {
+    fn bytes_only(&self) {}
+}
+type IntVec = Vec<i32>;
+
+/// [`IntVec::bytes_only`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_dyn_trait_method() {
+    check_doc_links(
+        r#"
+//- minicore: deref, sized
+struct Box<T: ?Sized>(*const T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+
+trait Trait {
+    fn method(&self);
+     //^^^^^^ DynTrait::method
+     //^^^^^^ BoxedTrait::method
+}
+type DynTrait = dyn Trait;
+type BoxedTrait = Box<dyn Trait>;
+
+/// [`DynTrait::method`]
+/// [`BoxedTrait::method`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_blanket_impl_method() {
+    check_doc_links(
+        r#"
+trait Blanket {
+    fn blanket_method(&self);
+     //^^^^^^^^^^^^^^ MyType::blanket_method
+}
+impl<T> Blanket for T {
+    fn blanket_method(&self) {}
+}
+
+struct MyType;
+
+/// [`MyType::blanket_method`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_macro_generated_impl_method() {
+    check_doc_links(
+        r#"
+macro_rules! impl_method {
+    ($ty:ident, $name:ident) => {
+        impl $ty {
+            pub fn $name(&self) {}
+        }
+    };
+}
+
+struct MyType;
+impl_method!(MyType, generated);
+                   //^^^^^^^^^ MyType::generated
+
+/// [`MyType::generated`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_bounded_blanket_impl_method() {
+    check_doc_links(
+        r#"
+trait Bound {}
+trait Blanket {
+    fn blanket_method(&self);
+     //^^^^^^^^^^^^^^ MyType::blanket_method
+}
+impl<T: Bound> Blanket for T {
+    fn blanket_method(&self) {}
+}
+
+struct MyType;
+impl Bound for MyType {}
+
+/// [`MyType::blanket_method`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_bounded_blanket_impl_method_unsatisfied() {
+    check_doc_links_unresolved(
+        r#"
+trait Bound {}
+trait Blanket {
+    fn blanket_method(&self);
+}
+impl<T: Bound> Blanket for T {
+    fn blanket_method(&self) {}
+}
+
+struct MyType;
+
+/// [`MyType::blanket_method`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_derived_trait_method() {
+    let (analysis, position) = fixture::position(
+        r#"
+//- minicore: derive, clone
+#[derive(Clone)]
+struct MyType;
+
+/// [`MyType::clone`]
+fn main$0() {}
+"#,
+    );
+    let sema = &Semantics::new(&*analysis.db);
+    let (cursor_def, docs) = def_under_cursor(sema, &position);
+    let (_, link, ns) = extract_definitions_from_docs(&docs).pop().unwrap();
+    let def = resolve_doc_path_for_def(sema.db, cursor_def, &link, ns)
+        .unwrap_or_else(|| panic!("Failed to resolve {link}"));
+    let Definition::Function(func) = def else { panic!("expected a method, got {def:?}") };
+    assert_eq!(func.name(sema.db).display(sema.db).to_string(), "clone");
+    let trait_ =
+        func.as_assoc_item(sema.db).and_then(|it| it.containing_trait_or_trait_impl(sema.db));
+    assert_eq!(
+        trait_.map(|it| it.name(sema.db).display(sema.db).to_string()).as_deref(),
+        Some("Clone")
+    );
+}
+
+#[test]
+fn doc_links_inherent_impl_in_other_module() {
+    check_doc_links(
+        r#"
+mod a {
+    pub struct Foo;
+}
+mod b {
+    impl crate::a::Foo {
+        pub fn from_b() {}
+             //^^^^^^ Foo::from_b
+    }
+}
+use a::Foo;
+
+/// [`Foo::from_b`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_record_variant_field() {
+    check_doc_links(
+        r#"
+enum Foo {
+    Bar { x: i32 },
+        //^ Foo::Bar::x
+}
+
+/// [`Foo::Bar::x`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_extern_block_items() {
+    check_doc_links(
+        r#"
+extern "C" {
+    fn my_extern_fn();
+    // ^^^^^^^^^^^^ my_extern_fn
+    static MY_EXTERN_STATIC: i32;
+        // ^^^^^^^^^^^^^^^^ MY_EXTERN_STATIC
+}
+
+/// [`my_extern_fn`]
+/// [`MY_EXTERN_STATIC`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_local_item_shadows_prelude() {
+    check_doc_links(
+        r#"
+//- minicore: result
+pub struct Result;
+//         ^^^^^^ Result
+
+/// [`Result`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_edition_2021_prelude() {
+    check_doc_links(
+        r#"
+//- /main.rs crate:main deps:std edition:2021
+/// [`TryFrom`]
+fn main$0() {}
+//- /std.rs crate:std
+pub mod convert {
+    pub trait TryFrom<T> {}
+            //^^^^^^^ TryFrom
+}
+pub mod prelude {
+    pub mod rust_2018 {}
+    pub mod rust_2021 {
+        pub use crate::convert::TryFrom;
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_crate_prefix_from_nested_module() {
+    check_doc_links(
+        r#"
+pub struct Foo;
+        // ^^^ crate::Foo
+pub mod top {
+    pub struct Item;
+            // ^^^^ crate::top::Item
+    pub mod inner {
+        pub struct Foo;
+        pub mod deep {
+            /// [`crate::Foo`]
+            /// [`crate::top::Item`]
+            pub fn f$0() {}
+        }
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_self_and_super_at_crate_root() {
+    check_doc_links(
+        r#"
+pub struct Foo;
+        // ^^^ self::Foo
+
+/// [`self::Foo`]
+fn main$0() {}
+"#,
+    );
+    check_doc_links_unresolved(
+        r#"
+pub struct Foo;
+
+/// [`super::Foo`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_value_disambiguator_picks_const_over_struct() {
+    check_doc_links(
+        r#"
+struct Foo {}
+    // ^^^ Foo
+#[allow(non_upper_case_globals)]
+const Foo: u32 = 0;
+   // ^^^ Foo
+
+/// [value@Foo]
+/// [type@Foo]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_multi_hop_reexport_chain() {
+    check_doc_links(
+        r#"
+pub mod a {
+    pub use self::b::Item;
+    pub mod b {
+        pub use self::c::Item;
+        pub mod c {
+            pub struct Item;
+                    // ^^^^ a::Item
+        }
+    }
+}
+
+/// [`a::Item`]
+fn main$0() {}
+"#,
+    );
+}
+
+#[test]
+fn doc_links_method_in_cfg_active_impl() {
+    check_doc_links(
+        r#"
+struct Foo;
+
+#[cfg(any())]
+impl Foo {
+    fn method(&self) {}
+}
+
+#[cfg(all())]
+impl Foo {
+    fn method(&self) {}
+    // ^^^^^^ Foo::method
+}
+
+/// [`Foo::method`]
+fn main$0() {}
+"#,
+    );
+}
+
 #[test]
 fn rewrite_html_root_url() {
     check_rewrite(
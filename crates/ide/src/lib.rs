@@ -121,8 +121,8 @@ pub use ide_assists::{
     Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, SingleResolve,
 };
 pub use ide_completion::{
-    CallableSnippets, CompletionConfig, CompletionItem, CompletionItemKind, CompletionRelevance,
-    Snippet, SnippetScope,
+    CallableSnippets, CompletionConfig, CompletionId, CompletionItem, CompletionItemCategory,
+    CompletionItemKind, CompletionRelevance, Snippet, SnippetScope,
 };
 pub use ide_db::{
     base_db::{
@@ -498,6 +498,15 @@ impl Analysis {
         })
     }
 
+    /// Returns all intra-doc links in the documentation of the item at `position`, together with
+    /// the navigation targets they resolve to. Unresolved links map to `None`.
+    pub fn doc_links(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Vec<(String, Option<Vec<NavigationTarget>>)>> {
+        self.with_db(|db| doc_links::doc_links(db, position).unwrap_or_default())
+    }
+
     /// Computes parameter information at the given position.
     pub fn signature_help(&self, position: FilePosition) -> Cancellable<Option<SignatureHelp>> {
         self.with_db(|db| signature_help::signature_help(db, position))
@@ -216,6 +216,9 @@ config_data! {
         /// Aliased as `"checkOnSave.targets"`.
         check_targets | checkOnSave_targets | checkOnSave_target: Option<CheckOnSaveTargets> = "null",
 
+        /// Whether the completions that automatically add imports should only offer items defined in
+        /// the current crate or its direct dependencies, leaving out items of transitive dependencies.
+        completion_autoimport_directDepsOnly: bool = "false",
         /// Toggles the additional completions that automatically add imports when completed.
         /// Note that your client must specify the `additionalTextEdits` LSP client capability to truly have this feature enabled.
         completion_autoimport_enable: bool       = "true",
@@ -230,6 +233,10 @@ config_data! {
         completion_limit: Option<usize> = "null",
         /// Whether to show postfix snippets like `dbg`, `if`, `not`, etc.
         completion_postfix_enable: bool         = "true",
+        /// Whether to skip rendering completions whose name cannot match the identifier typed so far.
+        /// This reduces the completion work in large crates, but clients that keep filtering a previous
+        /// result while the identifier is edited may miss items.
+        completion_prefilter_enable: bool = "false",
         /// Enables completions of private items and fields that are defined in the current workspace even if they are not visible at the current position.
         completion_privateEditable_enable: bool = "false",
         /// Custom completion snippets.
@@ -1503,6 +1510,7 @@ impl Config {
                 && completion_item_edit_resolve(&self.caps),
             enable_self_on_the_fly: self.data.completion_autoself_enable,
             enable_private_editable: self.data.completion_privateEditable_enable,
+            enable_prefilter: self.data.completion_prefilter_enable,
             full_function_signatures: self.data.completion_fullFunctionSignatures_enable,
             callable: match self.data.completion_callable_snippets {
                 CallableCompletionDef::FillArguments => Some(CallableSnippets::FillArguments),
@@ -1512,6 +1520,7 @@ impl Config {
             insert_use: self.insert_use_config(),
             prefer_no_std: self.data.imports_preferNoStd,
             prefer_prelude: self.data.imports_preferPrelude,
+            imports_from_direct_deps_only: self.data.completion_autoimport_directDepsOnly,
             snippet_cap: SnippetCap::new(try_or_def!(
                 self.caps
                     .text_document
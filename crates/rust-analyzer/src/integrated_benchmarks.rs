@@ -132,6 +132,7 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_prefilter: false,
             full_function_signatures: false,
             callable: Some(CallableSnippets::FillArguments),
             snippet_cap: SnippetCap::new(true),
@@ -145,6 +146,7 @@ fn integrated_completion_benchmark() {
             snippets: Vec::new(),
             prefer_no_std: false,
             prefer_prelude: true,
+            imports_from_direct_deps_only: false,
             limit: None,
         };
         let position =
@@ -176,6 +178,7 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_prefilter: false,
             full_function_signatures: false,
             callable: Some(CallableSnippets::FillArguments),
             snippet_cap: SnippetCap::new(true),
@@ -189,6 +192,7 @@ fn integrated_completion_benchmark() {
             snippets: Vec::new(),
             prefer_no_std: false,
             prefer_prelude: true,
+            imports_from_direct_deps_only: false,
             limit: None,
         };
         let position =
@@ -217,6 +221,7 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_prefilter: false,
             full_function_signatures: false,
             callable: Some(CallableSnippets::FillArguments),
             snippet_cap: SnippetCap::new(true),
@@ -230,6 +235,7 @@ fn integrated_completion_benchmark() {
             snippets: Vec::new(),
             prefer_no_std: false,
             prefer_prelude: true,
+            imports_from_direct_deps_only: false,
             limit: None,
         };
         let position =
@@ -196,9 +196,11 @@ impl ChangeFixture {
                 let prev = crates.insert(crate_name.clone(), crate_id);
                 assert!(prev.is_none(), "multiple crates with same name: {}", crate_name);
                 for dep in meta.deps {
-                    let prelude = meta.extern_prelude.contains(&dep);
-                    let dep = CrateName::normalize_dashes(&dep);
-                    crate_deps.push((crate_name.clone(), dep, prelude))
+                    let (name, krate) = dep.split_once('=').unwrap_or((&dep, &dep));
+                    let prelude = meta.extern_prelude.iter().any(|it| it == name);
+                    let name = CrateName::normalize_dashes(name);
+                    let krate = CrateName::normalize_dashes(krate);
+                    crate_deps.push((crate_name.clone(), name, krate, prelude))
                 }
             } else if meta.path == "/main.rs" || meta.path == "/lib.rs" {
                 assert!(default_crate_root.is_none());
@@ -234,14 +236,14 @@ impl ChangeFixture {
                 toolchain.clone(),
             );
         } else {
-            for (from, to, prelude) in crate_deps {
+            for (from, name, to, prelude) in crate_deps {
                 let from_id = crates[&from];
                 let to_id = crates[&to];
                 crate_graph
                     .add_dep(
                         from_id,
                         Dependency::with_prelude(
-                            CrateName::new(&to).unwrap(),
+                            CrateName::new(&name).unwrap(),
                             to_id,
                             prelude,
                             DependencyKind::Normal,
@@ -496,7 +498,9 @@ impl FileMeta {
         Self {
             path: f.path,
             krate: f.krate.map(|it| parse_crate(it, current_source_root_kind, f.library)),
-            extern_prelude: f.extern_prelude.unwrap_or_else(|| deps.clone()),
+            extern_prelude: f.extern_prelude.unwrap_or_else(|| {
+                deps.iter().map(|dep| dep.split('=').next().unwrap_or(dep).to_owned()).collect()
+            }),
             deps,
             cfg,
             edition: f.edition.map_or(Edition::CURRENT, |v| Edition::from_str(&v).unwrap()),
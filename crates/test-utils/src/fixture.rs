@@ -79,6 +79,9 @@ pub struct Fixture {
     /// Specifies dependencies of this crate. This must be used with `crate` meta.
     ///
     /// Syntax: `deps:hir-def,ide-assists`
+    ///
+    /// A dependency can be renamed like Cargo's `package` key allows with
+    /// `deps:local_name=crate_name`.
     pub deps: Vec<String>,
     /// Limits crates in the extern prelude. The set of crate names must be a
     /// subset of `deps`. This must be used with `crate` meta.
@@ -291,7 +294,7 @@ impl FixtureWithProjectMeta {
 
         for prelude_dep in extern_prelude.iter().flatten() {
             assert!(
-                deps.contains(prelude_dep),
+                deps.iter().any(|dep| dep.split('=').next() == Some(prelude_dep)),
                 "extern-prelude {extern_prelude:?} must be a subset of deps {deps:?}"
             );
         }
@@ -31,6 +31,7 @@
 //!     fmt: result, transmute, coerce_unsized
 //!     fn:
 //!     from: sized
+//!     from_iterator: iterator, sized
 //!     future: pin
 //!     generator: pin
 //!     hash:
@@ -41,6 +42,7 @@
 //!     iterator: option
 //!     iterators: iterator, fn
 //!     manually_drop: drop
+//!     matches:
 //!     non_null:
 //!     non_zero:
 //!     option: panic
@@ -1289,6 +1291,14 @@ pub mod iter {
                     loop {}
                 }
                 // endregion:iterators
+                // region:from_iterator
+                fn collect<B: super::collect::FromIterator<Self::Item>>(self) -> B
+                where
+                    Self: Sized,
+                {
+                    loop {}
+                }
+                // endregion:from_iterator
             }
             impl<I: Iterator + ?Sized> Iterator for &mut I {
                 type Item = I::Item;
@@ -1300,6 +1310,11 @@ pub mod iter {
         pub use self::iterator::Iterator;
 
         mod collect {
+            // region:from_iterator
+            pub trait FromIterator<A>: Sized {
+                fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
+            }
+            // endregion:from_iterator
             pub trait IntoIterator {
                 type Item;
                 type IntoIter: Iterator<Item = Self::Item>;
@@ -1336,8 +1351,14 @@ pub mod iter {
             }
         }
         pub use self::collect::IntoIterator;
+        // region:from_iterator
+        pub use self::collect::FromIterator;
+        // endregion:from_iterator
     }
     pub use self::traits::{IntoIterator, Iterator};
+    // region:from_iterator
+    pub use self::traits::FromIterator;
+    // endregion:from_iterator
 }
 // endregion:iterator
 
@@ -1390,6 +1411,18 @@ mod macros {
     }
     // endregion:assert
 
+    // region:matches
+    #[macro_export]
+    macro_rules! matches {
+        ($expression:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+            match $expression {
+                $pattern $(if $guard)? => true,
+                _ => false
+            }
+        };
+    }
+    // endregion:matches
+
     // region:fmt
     #[allow_internal_unstable(fmt_internals, const_fmt_arguments_new)]
     #[macro_export]